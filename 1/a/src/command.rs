@@ -0,0 +1,41 @@
+use std::time::Instant;
+
+use aoc_common::cli::DayOption;
+
+use crate::{parse_file, product_of_sum_with_details, TARGET};
+
+pub const OPTIONS: &[DayOption] = &[
+    DayOption { name: "k", value: Some("<n>"), description: "Look for this many entries instead" },
+    DayOption { name: "target", value: Some("<n>"), description: "Look for entries that sum to this instead of 2020" },
+];
+
+fn parse_number<T: std::str::FromStr>(name: &str, value: &str) -> T {
+    return match value.trim().parse() {
+        Err(_) => aoc_common::exit_with_error(format!("Couldn't parse the --{} '{}': expected a number", name, value)),
+        Ok(number) => number
+    };
+}
+
+pub fn main(part: usize, default_k: usize) {
+    let options = aoc_common::cli::parse_args_with(OPTIONS);
+    let k = options.value("k").map_or(default_k, |value| parse_number("k", value));
+    let target = options.value("target").map_or(TARGET, |value| parse_number("target", value));
+
+    let start = Instant::now();
+    let numbers = match parse_file(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(numbers) => numbers
+    };
+    let (product, mut details) = match product_of_sum_with_details(&numbers, k, target) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(product) => product
+    };
+
+    if options.json {
+        details["input"] = aoc_common::json!(options.input.to_string());
+        details["description"] = aoc_common::json!(format!("Product of the {} entries that sum to {}", k, target));
+        aoc_common::output::print_json(1, part, &product, start.elapsed(), details);
+    } else {
+        println!("Product: {}", product);
+    }
+}
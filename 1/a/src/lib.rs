@@ -0,0 +1,203 @@
+use std::error;
+use std::fmt;
+
+use std::cmp::Ordering;
+
+use aoc_common::{json, Input, InputError, Value};
+
+pub mod command;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    NoSolution { k: usize, target: i64 },
+    TooLarge { k: usize, target: i64 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::NoSolution { k, target } => write!(f, "No {} entries sum to {}", k, target),
+            Error::TooLarge { k, target } => write!(f, "The product of the {} entries that sum to {} doesn't fit in a 64 bit number", k, target),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+pub const TARGET: i64 = 2020;
+
+pub fn parse_file(input: &Input) -> Result<Vec<i64>, Error> {
+    return Ok(aoc_common::parse_lines(input)?);
+}
+
+fn push_found(chosen: &mut Vec<usize>, positions: &[usize], found: &mut Vec<Vec<usize>>) {
+    let mut combination = chosen.clone();
+    combination.extend_from_slice(positions);
+    found.push(combination);
+}
+
+// Both pointers step over whole runs of equal values, so every pairing of duplicate entries is found.
+fn find_pairs(sorted: &Vec<i64>, start: usize, target: i64, chosen: &mut Vec<usize>, found: &mut Vec<Vec<usize>>) {
+    if start + 1 >= sorted.len() {
+        return;
+    }
+
+    let mut low = start;
+    let mut high = sorted.len() - 1;
+    while low < high {
+        // Two entries only overflow when they have the same sign, which says which way past the target they went.
+        let order = match sorted[low].checked_add(sorted[high]) {
+            None if sorted[low] < 0 => Ordering::Less,
+            None => Ordering::Greater,
+            Some(sum) => sum.cmp(&target),
+        };
+        if order == Ordering::Less {
+            low += 1;
+        } else if order == Ordering::Greater {
+            high -= 1;
+        } else if sorted[low] == sorted[high] {
+            // Everything in between has the same value, so any two of them make the pair.
+            for a in low..=high {
+                for b in (a + 1)..=high {
+                    push_found(chosen, &[a, b], found);
+                }
+            }
+            return;
+        } else {
+            let mut low_end = low + 1;
+            while sorted[low_end] == sorted[low] {
+                low_end += 1;
+            }
+            let mut high_start = high - 1;
+            while sorted[high_start] == sorted[high] {
+                high_start -= 1;
+            }
+
+            for a in low..low_end {
+                for b in (high_start + 1)..=high {
+                    push_found(chosen, &[a, b], found);
+                }
+            }
+            low = low_end;
+            high = high_start;
+        }
+    }
+}
+
+fn find_sums(sorted: &Vec<i64>, start: usize, k: usize, target: i64, chosen: &mut Vec<usize>, found: &mut Vec<Vec<usize>>) {
+    match k {
+        0 => {
+            if target == 0 {
+                push_found(chosen, &[], found);
+            }
+        },
+        1 => {
+            for position in start..sorted.len() {
+                if sorted[position] == target {
+                    push_found(chosen, &[position], found);
+                }
+            }
+        },
+        2 => find_pairs(sorted, start, target, chosen, found),
+        _ => {
+            for position in start..sorted.len() {
+                if position + k > sorted.len() {
+                    break;
+                }
+                // The smallest sum left starts here, and it only grows from here on. If it doesn't fit in an i64 the
+                // search just goes on without pruning.
+                let smallest = sorted[position..(position + k)].iter().try_fold(0i64, |sum, value| sum.checked_add(*value));
+                if smallest.is_some_and(|smallest| smallest > target) {
+                    break;
+                }
+                let remainder = match target.checked_sub(sorted[position]) {
+                    None => continue,
+                    Some(remainder) => remainder
+                };
+
+                chosen.push(position);
+                find_sums(sorted, position + 1, k - 1, remainder, chosen, found);
+                chosen.pop();
+            }
+        },
+    }
+}
+
+// Every combination of `k` different entries that sums to `target`, as indices into `numbers` in increasing order.
+// Entries with the same value are still different entries. The numbers are sorted so each level can fix its smallest
+// entry and leave the last two to a two pointer scan, which makes k entries O(n^(k-1)) rather than O(n^k). A
+// combination is only found while what's left of the target after each entry fixed that way fits in an i64.
+pub fn k_sum(numbers: &Vec<i64>, k: usize, target: i64) -> Vec<Vec<usize>> {
+    let mut order: Vec<usize> = (0..numbers.len()).collect();
+    order.sort_by_key(|index| numbers[*index]);
+    let sorted: Vec<i64> = order.iter().map(|index| numbers[*index]).collect();
+
+    let mut found = Vec::new();
+    find_sums(&sorted, 0, k, target, &mut Vec::new(), &mut found);
+
+    let mut combinations: Vec<Vec<usize>> = found
+        .iter()
+        .map(|positions| {
+            let mut indices: Vec<usize> = positions.iter().map(|position| order[*position]).collect();
+            indices.sort();
+            indices
+        })
+        .collect();
+    combinations.sort();
+
+    return combinations;
+}
+
+// The first `k` entries (by index) that sum to `target`, as indices, and their product.
+fn first_sum(numbers: &Vec<i64>, k: usize, target: i64) -> Result<(Vec<usize>, i64), Error> {
+    let indices = match k_sum(numbers, k, target).into_iter().next() {
+        None => return Err(Error::NoSolution { k: k, target: target }),
+        Some(indices) => indices
+    };
+    let product = indices
+        .iter()
+        .try_fold(1i64, |product, index| product.checked_mul(numbers[*index]))
+        .ok_or(Error::TooLarge { k: k, target: target })?;
+
+    return Ok((indices, product));
+}
+
+pub fn product_of_sum(numbers: &Vec<i64>, k: usize, target: i64) -> Result<i64, Error> {
+    return Ok(first_sum(numbers, k, target)?.1);
+}
+
+// The product along with the entries that went into it and their indices.
+pub fn product_of_sum_with_details(numbers: &Vec<i64>, k: usize, target: i64) -> Result<(i64, Value), Error> {
+    let (indices, product) = first_sum(numbers, k, target)?;
+    let entries: Vec<i64> = indices.iter().map(|index| numbers[*index]).collect();
+    return Ok((product, json!({ "k": k, "target": target, "entries": entries, "indices": indices })));
+}
+
+pub fn solve(numbers: &Vec<i64>) -> Result<i64, Error> {
+    return product_of_sum(numbers, 2, TARGET);
+}
+
+pub fn part1(input: &Input) -> Result<i64, Error> {
+    let numbers = parse_file(input)?;
+    return solve(&numbers);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let numbers = parse_file(input)?;
+    return product_of_sum_with_details(&numbers, 2, TARGET);
+}
@@ -0,0 +1,3 @@
+fn main() {
+    day1a::command::main(1, 2);
+}
@@ -0,0 +1,17 @@
+use aoc_common::{Input, Value};
+
+pub use day1a::{command, k_sum, parse_file, product_of_sum, product_of_sum_with_details, Error, TARGET};
+
+pub fn solve(numbers: &Vec<i64>) -> Result<i64, Error> {
+    return product_of_sum(numbers, 3, TARGET);
+}
+
+pub fn part2(input: &Input) -> Result<i64, Error> {
+    let numbers = parse_file(input)?;
+    return solve(&numbers);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let numbers = parse_file(input)?;
+    return product_of_sum_with_details(&numbers, 3, TARGET);
+}
@@ -0,0 +1,3 @@
+fn main() {
+    day1b::command::main(2, 3);
+}
@@ -0,0 +1,85 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    NoAdapters,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::NoAdapters => write!(f, "There are no adapters in the bag"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<i64>, Error> {
+    let mut adapters: Vec<i64> = aoc_common::parse_lines(input)?;
+    
+    let max_joltage = *adapters.iter().max().ok_or(Error::NoAdapters)?;
+
+    let mut joltages: Vec<i64> = Vec::new();
+
+    joltages.push(0);
+    joltages.append(&mut adapters);
+    joltages.push(max_joltage + 3);
+
+    joltages.sort();
+
+    return Ok(joltages);
+}
+
+fn find_differences(joltages: &Vec<i64>) -> (i64, i64) {
+    let mut one_jolt_differences: i64 = 0;
+    let mut three_jolt_differences: i64 = 0;
+
+    for i in 1..joltages.len() {
+        let difference = joltages[i] - joltages[i - 1];
+        if difference == 1 {
+            one_jolt_differences+= 1;
+        } else if difference == 3 {
+            three_jolt_differences += 1;
+        }
+    }
+
+    return (one_jolt_differences, three_jolt_differences);
+}
+
+pub fn solve(joltages: &Vec<i64>) -> Result<i64, Error> {
+    return Ok(solve_with_details(joltages)?.0);
+}
+
+pub fn solve_with_details(joltages: &Vec<i64>) -> Result<(i64, Value), Error> {
+    let (ojd, tjd) = find_differences(joltages);
+    return Ok((ojd * tjd, json!({ "adapters": joltages.len() - 2, "one_jolt_differences": ojd, "three_jolt_differences": tjd })));
+}
+
+pub fn part1(input: &Input) -> Result<i64, Error> {
+    let joltages = parse_file(input)?;
+    return solve(&joltages);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let joltages = parse_file(input)?;
+    return solve_with_details(&joltages);
+}
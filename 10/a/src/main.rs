@@ -1,54 +1,20 @@
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use std::time::Instant;
 
-fn parse_file(file_name: &Path) -> Vec<i64> {
-    let file = match File::open(&file_name) {
-        Err(why) => panic!("Couldn't open {}: {}", file_name.display(), why),
-        Ok(file) => file
-    };
-
-    let lines = io::BufReader::new(file).lines();
-
-    let mut adapters: Vec<i64> = lines.map(|line| line.unwrap().parse::<i64>().unwrap())
-                .collect();
-    
-    let max_joltage = *adapters.iter().max().unwrap();
-
-    let mut joltages: Vec<i64> = Vec::new();
-
-    joltages.push(0);
-    joltages.append(&mut adapters);
-    joltages.push(max_joltage + 3);
-
-    joltages.sort();
-
-    return joltages;
-}
-
-fn find_differences(joltages: &Vec<i64>) -> (i64, i64) {
-    let mut one_jolt_differences: i64 = 0;
-    let mut three_jolt_differences: i64 = 0;
-
-    for i in 1..joltages.len() {
-        let difference = joltages[i] - joltages[i - 1];
-        if difference == 1 {
-            one_jolt_differences+= 1;
-        } else if difference == 3 {
-            three_jolt_differences += 1;
-        }
-    }
-
-    return (one_jolt_differences, three_jolt_differences);
-}
+use day10a::part1_with_details;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let input_file = Path::new(&args[1]);
+    let options = aoc_common::cli::parse_args();
 
-    let joltages = parse_file(&input_file);
-    let (ojd, tjd) = find_differences(&joltages);
-    
-    print!("Numbers: {:?}", ojd * tjd);
+    let start = Instant::now();
+    let (number, mut details) = match part1_with_details(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(found) => found
+    };
+    if options.json {
+        details["input"] = aoc_common::json!(options.input.to_string());
+        details["description"] = aoc_common::json!("1 jolt differences multiplied by 3 jolt differences");
+        aoc_common::output::print_json(10, 1, &number, start.elapsed(), details);
+    } else {
+        print!("Numbers: {:?}", number);
+    }
 }
@@ -0,0 +1,88 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    NoAdapters,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::NoAdapters => write!(f, "There are no adapters in the bag"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<i64>, Error> {
+    let mut adapters: Vec<i64> = aoc_common::parse_lines(input)?;
+    
+    let max_joltage = *adapters.iter().max().ok_or(Error::NoAdapters)?;
+
+    let mut joltages: Vec<i64> = Vec::new();
+
+    joltages.push(0);
+    joltages.append(&mut adapters);
+    joltages.push(max_joltage + 3);
+
+    joltages.sort();
+
+    return Ok(joltages);
+}
+
+fn find_distinct_paths(joltages: &Vec<i64>) -> i64 {
+    let mut paths: Vec<i64> = vec![0; joltages.len()];
+    paths[0] = 1;
+    for i in 0..joltages.len() - 1 {
+        for j in i + 1..joltages.len() {
+            if joltages[j] > (joltages[i] + 3) {
+                break;
+            }
+
+            paths[j] += paths[i];
+        }
+    }
+
+    return *paths.last().unwrap();
+}
+
+pub fn solve(joltages: &Vec<i64>) -> Result<i64, Error> {
+    return Ok(solve_with_details(joltages)?.0);
+}
+
+pub fn solve_with_details(joltages: &Vec<i64>) -> Result<(i64, Value), Error> {
+    if joltages.len() < 2 {
+        return Err(Error::NoAdapters);
+    }
+
+    return Ok((find_distinct_paths(joltages), json!({ "adapters": joltages.len() - 2, "device_joltage": joltages[joltages.len() - 1] })));
+}
+
+pub fn part2(input: &Input) -> Result<i64, Error> {
+    let joltages = parse_file(input)?;
+    return solve(&joltages);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let joltages = parse_file(input)?;
+    return solve_with_details(&joltages);
+}
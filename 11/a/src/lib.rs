@@ -0,0 +1,167 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::trace;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_seat(cell: char) -> Option<char> {
+    return match cell {
+        '.' | 'L' | '#' => Some(cell),
+        _ => None
+    };
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Vec<char>>, Error> {
+    return Ok(aoc_common::parse_grid(input, "'.', 'L' or '#'", parse_seat)?);
+}
+
+struct Change {
+    row: usize,
+    column: usize,
+}
+
+fn count_occupied_around(map: &Vec<Vec<char>>, row: i32, column: i32) -> usize {
+    let mut occupied = 0;
+
+    for i in -1..2 {
+        for j in -1..2 {
+            if j == 0 && i == 0 {
+                continue;
+            }
+
+            let check_row = row + i;
+            let check_col = column + j;
+
+            if check_row < 0 || check_row >= map.len() as i32 {
+                continue;
+            }
+
+            if check_col < 0 || check_col >= map[0].len() as i32 { 
+                continue;
+            }
+
+            if map[check_row as usize][check_col as usize] == '#' {
+                occupied += 1;
+            }
+        }
+    }
+
+    return occupied;
+}
+
+fn find_changes(map: &Vec<Vec<char>>) -> Vec<Change> {
+    let mut changes = Vec::new();
+    
+    for i in 0..map.len() {
+        for j in 0..map[0].len() {
+            let current_value = map[i as usize][j as usize];
+
+            if current_value != '.' {
+                let occupied = count_occupied_around(map, i as i32, j as i32);
+                if (occupied == 0 && current_value == 'L') || (occupied >= 4 && current_value == '#') {
+                    changes.push(
+                        Change {
+                            row: i,
+                            column: j,
+                        }
+                    );
+                }
+            }
+        }
+    }
+
+    return changes;
+}
+
+fn apply_changes(map: &mut Vec<Vec<char>>, changes: &Vec<Change>) {
+    for change in changes {
+        if map[change.row][change.column] == 'L' {
+            map[change.row][change.column] = '#';
+        } else {
+            map[change.row][change.column] = 'L';
+        }
+    }
+}
+
+fn count_occupied(map: &Vec<Vec<char>>) -> usize {
+    let mut occupied = 0;
+
+    for row in map {
+        for seat in row {
+            if *seat == '#' {
+                occupied += 1;
+            }
+        }
+    }
+
+    return occupied;
+}
+
+fn format_map(map: &Vec<Vec<char>>) -> String {
+    let mut formatted = String::new();
+    for row in map {
+        formatted.extend(row.iter());
+        formatted.push('\n');
+    }
+
+    return formatted;
+}
+
+pub fn solve(map: &Vec<Vec<char>>) -> Result<usize, Error> {
+    return Ok(solve_with_details(map)?.0);
+}
+
+// The occupied seats once nobody moves, along with how many rounds that took.
+pub fn solve_with_details(map: &Vec<Vec<char>>) -> Result<(usize, Value), Error> {
+    let mut map = map.clone();
+    let mut rounds = 0;
+
+    loop {
+        trace!("Seats:\n{}", format_map(&map));
+        let changes = find_changes(&map);
+        if changes.is_empty() {
+            let seats = map.iter().flatten().filter(|cell| **cell != '.').count();
+            return Ok((count_occupied(&map), json!({ "rounds": rounds, "seats": seats })));
+        }
+
+        apply_changes(&mut map, &changes);
+        rounds += 1;
+    }
+}
+
+pub fn part1(input: &Input) -> Result<usize, Error> {
+    let map = parse_file(input)?;
+    return solve(&map);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let map = parse_file(input)?;
+    return solve_with_details(&map);
+}
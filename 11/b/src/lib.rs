@@ -0,0 +1,175 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::trace;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_seat(cell: char) -> Option<char> {
+    return match cell {
+        '.' | 'L' | '#' => Some(cell),
+        _ => None
+    };
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Vec<char>>, Error> {
+    return Ok(aoc_common::parse_grid(input, "'.', 'L' or '#'", parse_seat)?);
+}
+
+struct Change {
+    row: usize,
+    column: usize,
+}
+
+fn find_first_visible_seat(map: &Vec<Vec<char>>, row: i32, column: i32, delta_row: i32, delta_column: i32) -> char {
+    let mut current_row = row + delta_row;
+    let mut current_col = column + delta_column;
+
+    let row_range = 0..(map.len() as i32);
+    let col_range = 0..(map[0].len() as i32);
+
+    while row_range.contains(&current_row) && col_range.contains(&current_col) {
+        if map[current_row as usize][current_col as usize] != '.' {
+            return map[current_row as usize][current_col as usize];
+        }
+        current_row += delta_row;
+        current_col += delta_column;
+    }
+
+    return '.';
+}
+
+fn count_occupied_around(map: &Vec<Vec<char>>, row: i32, column: i32) -> usize {
+    let mut occupied = 0;
+
+    for i in -1..2 {
+        for j in -1..2 {
+            if j == 0 && i == 0 {
+                continue;
+            }
+
+            let first_seat = find_first_visible_seat(map, row, column, i, j);
+            if first_seat == '#' {
+                occupied += 1;
+            }
+        }
+    }
+
+    return occupied;
+}
+
+fn find_changes(map: &Vec<Vec<char>>) -> Vec<Change> {
+    let mut changes = Vec::new();
+    
+    for i in 0..map.len() {
+        for j in 0..map[0].len() {
+            let current_value = map[i as usize][j as usize];
+
+            if current_value != '.' {
+                let occupied = count_occupied_around(map, i as i32, j as i32);
+                if (occupied == 0 && current_value == 'L') || (occupied >= 5 && current_value == '#') {
+                    changes.push(
+                        Change {
+                            row: i,
+                            column: j,
+                        }
+                    );
+                }
+            }
+        }
+    }
+
+    return changes;
+}
+
+fn apply_changes(map: &mut Vec<Vec<char>>, changes: &Vec<Change>) {
+    for change in changes {
+        if map[change.row][change.column] == 'L' {
+            map[change.row][change.column] = '#';
+        } else {
+            map[change.row][change.column] = 'L';
+        }
+    }
+}
+
+fn count_occupied(map: &Vec<Vec<char>>) -> usize {
+    let mut occupied = 0;
+
+    for row in map {
+        for seat in row {
+            if *seat == '#' {
+                occupied += 1;
+            }
+        }
+    }
+
+    return occupied;
+}
+
+fn format_map(map: &Vec<Vec<char>>) -> String {
+    let mut formatted = String::new();
+    for row in map {
+        formatted.extend(row.iter());
+        formatted.push('\n');
+    }
+
+    return formatted;
+}
+
+pub fn solve(map: &Vec<Vec<char>>) -> Result<usize, Error> {
+    return Ok(solve_with_details(map)?.0);
+}
+
+// The occupied seats once nobody moves, along with how many rounds that took.
+pub fn solve_with_details(map: &Vec<Vec<char>>) -> Result<(usize, Value), Error> {
+    let mut map = map.clone();
+    let mut rounds = 0;
+
+    loop {
+        trace!("Seats:\n{}", format_map(&map));
+        let changes = find_changes(&map);
+        if changes.is_empty() {
+            let seats = map.iter().flatten().filter(|cell| **cell != '.').count();
+            return Ok((count_occupied(&map), json!({ "rounds": rounds, "seats": seats })));
+        }
+
+        apply_changes(&mut map, &changes);
+        rounds += 1;
+    }
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let map = parse_file(input)?;
+    return solve(&map);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let map = parse_file(input)?;
+    return solve_with_details(&map);
+}
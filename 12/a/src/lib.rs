@@ -0,0 +1,151 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+// The headings in the order turning right goes through them.
+const CLOCKWISE: [Heading; 4] = [Heading::North, Heading::East, Heading::South, Heading::West];
+
+impl Heading {
+    // The heading after turning right this many quarter turns. The variants are declared in clockwise order too.
+    pub fn turned(self, quarter_turns: u32) -> Heading {
+        return CLOCKWISE[(self as usize + quarter_turns as usize) % 4];
+    }
+
+    // How far one step moves east and north.
+    pub fn offset(self) -> (i32, i32) {
+        return match self {
+            Heading::North => (0, 1),
+            Heading::East => (1, 0),
+            Heading::South => (0, -1),
+            Heading::West => (-1, 0),
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instruction {
+    Move { heading: Heading, distance: i32 },
+    // Turns are kept as quarter turns to the right, so turning left by 90 is 3 of them.
+    Turn { quarter_turns: u32 },
+    Forward { distance: i32 },
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    Overflow { line: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::Overflow { line } => write!(f, "The ship goes too far to fit in a 32 bit number on line {}", line),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_line(line_number: usize, value: &str) -> Result<Instruction, Error> {
+    let malformed = || Error::Malformed { line: line_number, text: String::from(value), expected: "one of N, S, E, W, L, R or F followed by a number, with turns in multiples of 90" };
+
+    let mut characters = value.chars();
+    let direction = characters.next().ok_or_else(malformed)?;
+    let amount = characters.as_str().parse::<i32>().map_err(|_| malformed())?;
+
+    let heading = |heading| Ok(Instruction::Move { heading: heading, distance: amount });
+    let quarter_turns = || if amount >= 0 && amount % 90 == 0 { Ok((amount / 90 % 4) as u32) } else { Err(malformed()) };
+    return match direction {
+        'N' => heading(Heading::North),
+        'E' => heading(Heading::East),
+        'S' => heading(Heading::South),
+        'W' => heading(Heading::West),
+        'F' => Ok(Instruction::Forward { distance: amount }),
+        'R' => Ok(Instruction::Turn { quarter_turns: quarter_turns()? }),
+        'L' => Ok(Instruction::Turn { quarter_turns: (4 - quarter_turns()?) % 4 }),
+        _ => Err(malformed()),
+    };
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Instruction>, Error> {
+    return aoc_common::parse_lines_with(input, parse_line);
+}
+
+// Where `distance` steps from `position` ends up, or None if it doesn't fit.
+fn step(position: i32, offset: i32, distance: i32) -> Option<i32> {
+    return offset.checked_mul(distance).and_then(|moved| position.checked_add(moved));
+}
+
+// How far the ship is from where it started, by Manhattan distance.
+fn distance_from_start(x: i32, y: i32) -> Option<i32> {
+    return x.checked_abs()?.checked_add(y.checked_abs()?);
+}
+
+// The distance along with where the ship ends up.
+fn follow_instructions(instructions: &Vec<Instruction>) -> Result<(i32, Value), Error> {
+    let mut facing = Heading::East;
+    let mut x: i32 = 0;
+    let mut y: i32 = 0;
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        let overflow = || Error::Overflow { line: index + 1 };
+        let (heading, distance) = match *instruction {
+            Instruction::Turn { quarter_turns } => {
+                facing = facing.turned(quarter_turns);
+                continue;
+            },
+            Instruction::Move { heading, distance } => (heading, distance),
+            Instruction::Forward { distance } => (facing, distance),
+        };
+
+        let (east, north) = heading.offset();
+        x = step(x, east, distance).ok_or_else(overflow)?;
+        y = step(y, north, distance).ok_or_else(overflow)?;
+    }
+
+    let distance = distance_from_start(x, y).ok_or(Error::Overflow { line: instructions.len() })?;
+    return Ok((distance, json!({ "east": x, "north": y })));
+}
+
+pub fn solve(instructions: &Vec<Instruction>) -> Result<i32, Error> {
+    return Ok(follow_instructions(instructions)?.0);
+}
+
+pub fn solve_with_details(instructions: &Vec<Instruction>) -> Result<(i32, Value), Error> {
+    return follow_instructions(instructions);
+}
+
+pub fn part1(input: &Input) -> Result<i32, Error> {
+    let instructions = parse_file(input)?;
+    return solve(&instructions);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i32, Value), Error> {
+    let instructions = parse_file(input)?;
+    return solve_with_details(&instructions);
+}
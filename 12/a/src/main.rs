@@ -1,99 +1,21 @@
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use std::time::Instant;
 
-struct Instruction {
-    direction: char,
-    amount: i32,
-}
-
-fn parse_line(line: std::io::Result<String>, file_name: String) -> Instruction {
-    let value = match line {
-        Err(why) => panic!("Couldn't read line from {}: {}", file_name, why),
-        Ok(line) => line
-    };
+use day12a::part1_with_details;
 
-    let (direction, amount) = value.split_at(1);
-    return Instruction {
-        direction: direction.chars().next().unwrap(),
-        amount: amount.parse::<i32>().unwrap()
-    };
-}
+fn main() {
+    let options = aoc_common::cli::parse_args();
 
-fn parse_file(file_name: &Path) -> Vec<Instruction> {
-    let file = match File::open(&file_name) {
-        Err(why) => panic!("Couldn't open {}: {}", file_name.display(), why),
-        Ok(file) => file
+    let start = Instant::now();
+    let (manhattan_distance, mut details) = match part1_with_details(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(found) => found
     };
 
-    let lines = io::BufReader::new(file).lines();
-
-    return lines.map(|line| parse_line(line, file_name.display().to_string()))
-                .collect();
-}
-
-fn rotate_direction(current_direction: char, rotation_direction: char, angle: i32) -> char {
-    let mut new_direction = current_direction;
-    let mut current_angle = angle;
-    while current_angle > 0 {
-        current_angle -= 90;
-        new_direction = match rotation_direction {
-            'R' => match new_direction {
-                        'N' => 'E',
-                        'E' => 'S',
-                        'S' => 'W',
-                        'W' => 'N',
-                        _ => panic!("Unknown direction: {}", new_direction),
-                    },
-            'L' => match new_direction {
-                'N' => 'W',
-                'W' => 'S',
-                'S' => 'E',
-                'E' => 'N',
-                _ => panic!("Unknown direction: {}", new_direction),
-            },
-            _ => panic!("Unknown rotation direction: {}", rotation_direction),
-        }
+    if options.json {
+        details["input"] = aoc_common::json!(options.input.to_string());
+        details["description"] = aoc_common::json!("Manhattan distance moving the ship");
+        aoc_common::output::print_json(12, 1, &manhattan_distance, start.elapsed(), details);
+    } else {
+        println!("Manhattan Distance: {}", manhattan_distance);
     }
-
-    return new_direction;
 }
-
-fn follow_instructions(instructions: &Vec<Instruction>) -> i32 {
-    let mut current_facing = 'E';
-    let mut x: i32 = 0;
-    let mut y: i32 = 0;
-
-    for instruction in instructions {
-        if instruction.direction == 'R' || instruction.direction == 'L' {
-            current_facing = rotate_direction(current_facing, instruction.direction, instruction.amount);
-            continue;
-        }
-
-        let mut direction_to_move = instruction.direction;
-        if instruction.direction == 'F' {
-            direction_to_move = current_facing;
-        }
-
-        match direction_to_move {
-            'N' => y += instruction.amount,
-            'E' => x += instruction.amount,
-            'S' => y -= instruction.amount,
-            'W' => x -= instruction.amount,
-            _ => panic!("Unknown direction {}", direction_to_move),
-        };
-    }
-
-    return x.abs() + y.abs();
-}
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let input_file = Path::new(&args[1]);
-
-    let instructions = parse_file(&input_file);
-    let manhattan_distance = follow_instructions(&instructions);
-
-    println!("Manhattan Distance: {}", manhattan_distance);
-}
\ No newline at end of file
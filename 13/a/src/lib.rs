@@ -0,0 +1,116 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+
+pub struct Schedule {
+    earliest_departure: i32,
+    busses: Vec<i32>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    Missing { line: usize, expected: &'static str },
+    NoBusses,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::Missing { line, expected } => write!(f, "Line {} is missing, expected {}", line, expected),
+            Error::NoBusses => write!(f, "There are no busses in service"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_line(line_number: usize, value: &String) -> Result<Vec<i32>, Error> {
+    let mut busses = Vec::new();
+    for id in value.split(',').filter(|id| *id != "x") {
+        match id.parse::<i32>() {
+            Ok(id) if id > 0 => busses.push(id),
+            _ => return Err(Error::Malformed { line: line_number, text: String::from(value), expected: "comma separated bus ids (positive numbers) or x" }),
+        }
+    }
+
+    return Ok(busses);
+}
+
+pub fn parse_file(input: &Input) -> Result<Schedule, Error> {
+    let lines = aoc_common::read_lines(input)?;
+    if lines.is_empty() {
+        return Err(Error::Missing { line: 1, expected: "the earliest departure time" });
+    }
+    if lines.len() < 2 {
+        return Err(Error::Missing { line: 2, expected: "the bus ids" });
+    }
+
+    let earliest_departure = match lines[0].parse::<i32>() {
+        Ok(time) if time >= 0 => time,
+        _ => return Err(Error::Malformed { line: 1, text: String::from(&lines[0]), expected: "the earliest departure time" })
+    };
+    let busses = parse_line(2, &lines[1])?;
+
+    return Ok(Schedule {
+        earliest_departure: earliest_departure,
+        busses: busses
+    });
+}
+
+fn find_earliest_bus(schedule: &Schedule) -> (i32, i32) {
+    let mut earliest_bus_id = -42;
+    let mut least_wait_time = i32::MAX;
+
+    for bus in &schedule.busses {
+        let modulo = schedule.earliest_departure % bus;
+        let wait_time = bus - modulo;
+        
+        if wait_time < least_wait_time {
+            earliest_bus_id = *bus;
+            least_wait_time = wait_time;
+        }
+    }
+
+    return (earliest_bus_id, least_wait_time);
+}
+
+pub fn solve(schedule: &Schedule) -> Result<i32, Error> {
+    return Ok(solve_with_details(schedule)?.0);
+}
+
+pub fn solve_with_details(schedule: &Schedule) -> Result<(i32, Value), Error> {
+    if schedule.busses.is_empty() {
+        return Err(Error::NoBusses);
+    }
+
+    let (bus_id, wait_time) = find_earliest_bus(schedule);
+    return Ok((bus_id * wait_time, json!({ "earliest_departure": schedule.earliest_departure, "bus": bus_id, "wait": wait_time })));
+}
+
+pub fn part1(input: &Input) -> Result<i32, Error> {
+    let schedule = parse_file(input)?;
+    return solve(&schedule);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i32, Value), Error> {
+    let schedule = parse_file(input)?;
+    return solve_with_details(&schedule);
+}
@@ -0,0 +1,130 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::debug;
+
+pub struct Bus {
+    id: i64,
+    offset: i64,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    Missing { line: usize, expected: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::Missing { line, expected } => write!(f, "Line {} is missing, expected {}", line, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_line(line_number: usize, value: &String) -> Result<Vec<Bus>, Error> {
+    let mut busses = Vec::new();
+    let mut current_index = 0;
+    for b in value.split(',') {
+        if b != "x" {
+            let id = match b.parse::<i64>() {
+                Ok(id) if id > 0 => id,
+                _ => return Err(Error::Malformed { line: line_number, text: String::from(value), expected: "comma separated bus ids (positive numbers) or x" })
+            };
+
+            busses.push(Bus {
+                id: id,
+                offset: -current_index
+            });
+        }
+        current_index += 1; 
+    }
+
+    return Ok(busses);
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Bus>, Error> {
+    let lines = aoc_common::read_lines(input)?;
+    let first_line = lines.first().ok_or(Error::Missing { line: 1, expected: "the bus ids" })?;
+    return parse_line(1, first_line);
+}
+
+fn find_mod_inverse(a: i64, n: i64) -> i64 {
+    let mut prev_r = n;
+    let mut curr_r = a;
+    let mut prev_t = 0;
+    let mut curr_t = 1;
+
+    while curr_r != 0 {
+        let quotient = prev_r / curr_r;
+
+        let new_r = prev_r - quotient * curr_r;
+        prev_r = curr_r;
+        curr_r = new_r;
+
+        let new_t = prev_t - quotient * curr_t;
+        prev_t = curr_t;
+        curr_t = new_t;        
+    }
+    
+    if prev_t < 0 {
+        prev_t += n;
+    }
+
+    return prev_t;
+}
+
+fn find_earliest_timestamp(busses: &Vec<Bus>) -> i64 {
+    let big_n: i64 = busses.iter().map(|bus| bus.id).product();
+    let mut x = 0;
+
+    for bus in busses {
+        let y = big_n / bus.id;
+        let inverse = find_mod_inverse(y, bus.id);
+        debug!("Bus {}, offset {} (big_n: {}, y: {}, inverse: {})", bus.id, bus.offset, big_n, y, inverse);
+
+        x += bus.offset * y * inverse;
+        x %= big_n;
+    }
+
+    return (x + big_n) % big_n;
+}
+
+pub fn solve(busses: &Vec<Bus>) -> Result<i64, Error> {
+    return Ok(find_earliest_timestamp(busses));
+}
+
+// The timestamp along with each bus and how long after it that bus leaves.
+pub fn solve_with_details(busses: &Vec<Bus>) -> Result<(i64, Value), Error> {
+    let busses_json: Vec<Value> = busses.iter().map(|bus| json!({ "bus": bus.id, "offset": bus.offset })).collect();
+    return Ok((find_earliest_timestamp(busses), json!({ "busses": busses_json })));
+}
+
+pub fn part2(input: &Input) -> Result<i64, Error> {
+    let busses = parse_file(input)?;
+    return solve(&busses);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let busses = parse_file(input)?;
+    return solve_with_details(&busses);
+}
@@ -0,0 +1,140 @@
+use std::error;
+use std::fmt;
+use std::collections::HashMap;
+
+use aoc_common::{json, Input, InputError, Value};
+use regex::Regex;
+use lazy_static::lazy_static;
+
+#[derive(Copy, Clone)]
+pub struct BinaryMask {
+    forced_ones: u64,
+    forced_zeros: u64,
+}
+
+pub struct MemoryAssignment {
+    address: usize,
+    value: u64,
+}
+
+pub enum Instruction {
+    Mask(BinaryMask),
+    Assignment(MemoryAssignment),
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+const INSTRUCTION_FORM: &str = "\"mask = <36 of 0, 1 or X>\" or \"mem[<address>] = <value>\"";
+
+fn parse_mask(line: &str) -> Instruction {
+    let value = line.chars().skip(7).collect::<String>();
+
+    let zeros = value.replace("X", "0");
+    let ones = value.replace("X", "1");
+
+    return Instruction::Mask(BinaryMask{
+        forced_ones: u64::from_str_radix(zeros.as_str(), 2).unwrap(),
+        forced_zeros: u64::from_str_radix(ones.as_str(), 2).unwrap()
+    });
+}
+
+
+lazy_static! {
+    static ref MASK_REGEX: Regex = Regex::new(r"^mask = [01X]{36}$").unwrap();
+    static ref ASSIGNMENT_REGEX: Regex = Regex::new(r"^mem\[(?P<address>\d+)] = (?P<value>\d+)$").unwrap();
+}
+
+fn parse_assignment(line: &str) -> Option<Instruction> {
+    let captures = ASSIGNMENT_REGEX.captures(line)?;
+    return Some(Instruction::Assignment(MemoryAssignment {
+        address: captures["address"].parse().ok()?,
+        value: captures["value"].parse().ok()?
+    }));
+}
+
+fn parse_line(line_number: usize, value: &str) -> Result<Instruction, Error> {
+    let instruction = if MASK_REGEX.is_match(value) {
+        Some(parse_mask(value))
+    } else {
+        parse_assignment(value)
+    };
+
+    return instruction.ok_or_else(|| Error::Malformed { line: line_number, text: String::from(value), expected: INSTRUCTION_FORM });
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Instruction>, Error> {
+    return aoc_common::parse_lines_with(input, parse_line);
+}
+
+// The sum of memory along with how many addresses were written to.
+fn execute(instructions: &Vec<Instruction>) -> (u64, usize) {
+    let mut current_mask = BinaryMask {
+        forced_zeros: u64::MAX,
+        forced_ones: 0
+    };
+
+    let mut memory: HashMap<usize, u64> = HashMap::new();
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mask(mask)=> current_mask = *mask,
+            Instruction::Assignment(assignment) => {
+                let mut new_value = assignment.value;
+                new_value = new_value | current_mask.forced_ones;
+                new_value = new_value & current_mask.forced_zeros;
+
+                let entry = memory.entry(assignment.address).or_default();
+                *entry = new_value;
+            }
+        };
+    }
+
+    return (memory.values().sum(), memory.len());
+}
+
+pub fn solve(instructions: &Vec<Instruction>) -> Result<u64, Error> {
+    return Ok(execute(instructions).0);
+}
+
+pub fn solve_with_details(instructions: &Vec<Instruction>) -> Result<(u64, Value), Error> {
+    let (sum, addresses) = execute(instructions);
+    return Ok((sum, json!({ "instructions": instructions.len(), "addresses": addresses })));
+}
+
+pub fn part1(input: &Input) -> Result<u64, Error> {
+    let instructions = parse_file(input)?;
+    return solve(&instructions);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(u64, Value), Error> {
+    let instructions = parse_file(input)?;
+    return solve_with_details(&instructions);
+}
@@ -0,0 +1,155 @@
+use std::error;
+use std::fmt;
+use std::collections::HashMap;
+
+use aoc_common::{json, Input, InputError, Value};
+use regex::Regex;
+use lazy_static::lazy_static;
+
+pub struct BinaryMask {
+    mask: String
+}
+
+pub struct MemoryAssignment {
+    address: u64,
+    value: u64,
+}
+
+pub enum Instruction {
+    Mask(BinaryMask),
+    Assignment(MemoryAssignment),
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+const INSTRUCTION_FORM: &str = "\"mask = <36 of 0, 1 or X>\" or \"mem[<address>] = <value>\"";
+
+fn parse_mask(line: &str) -> Instruction {
+    return Instruction::Mask(BinaryMask {
+        mask: line.chars().skip(7).collect::<String>()
+    });
+}
+
+
+lazy_static! {
+    static ref MASK_REGEX: Regex = Regex::new(r"^mask = [01X]{36}$").unwrap();
+    static ref ASSIGNMENT_REGEX: Regex = Regex::new(r"^mem\[(?P<address>\d+)] = (?P<value>\d+)$").unwrap();
+}
+
+fn parse_assignment(line: &str) -> Option<Instruction> {
+    let captures = ASSIGNMENT_REGEX.captures(line)?;
+    return Some(Instruction::Assignment(MemoryAssignment {
+        address: captures["address"].parse().ok()?,
+        value: captures["value"].parse().ok()?
+    }));
+}
+
+fn parse_line(line_number: usize, value: &str) -> Result<Instruction, Error> {
+    let instruction = if MASK_REGEX.is_match(value) {
+        Some(parse_mask(value))
+    } else {
+        parse_assignment(value)
+    };
+
+    return instruction.ok_or_else(|| Error::Malformed { line: line_number, text: String::from(value), expected: INSTRUCTION_FORM });
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Instruction>, Error> {
+    return aoc_common::parse_lines_with(input, parse_line);
+}
+
+fn apply_mask2(address: u64, mask: &[u8], current_index: usize, addresses: &mut Vec<u64>) {
+    if current_index >= mask.len() {
+        addresses.push(address);
+        return;
+    }
+
+    let bit_mask = 1 << (35 - current_index);
+
+
+    match mask[current_index] as char{
+        '1' => apply_mask2(address | bit_mask, mask, current_index + 1, addresses),
+        'X' => {
+            apply_mask2(address | bit_mask, mask, current_index + 1, addresses);
+            apply_mask2(address & (!bit_mask), mask, current_index + 1, addresses);
+        },
+        _ => apply_mask2(address, mask, current_index + 1, addresses)
+    };
+}
+
+fn apply_mask(address: u64, mask: &BinaryMask) -> Vec<u64> {
+    let mut addresses: Vec<u64> = Vec::new();
+    apply_mask2(address, mask.mask.as_bytes(), 0, &mut addresses);
+    return addresses;
+}
+
+// The sum of memory along with how many addresses were written to.
+fn execute(instructions: &Vec<Instruction>) -> (u64, usize) {
+    let initial_mask = BinaryMask {
+        mask: String::from("000000000000000000000000000000000000")
+    };
+    let mut current_mask = &initial_mask;
+
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mask(mask)=> current_mask = mask,
+            Instruction::Assignment(assignment) => {
+                for address in apply_mask(assignment.address, current_mask) {
+                    let entry = memory.entry(address).or_default();
+                    *entry = assignment.value;
+                }
+            }
+        };
+    }
+
+    return (memory.values().sum(), memory.len());
+}
+
+pub fn solve(instructions: &Vec<Instruction>) -> Result<u64, Error> {
+    return Ok(execute(instructions).0);
+}
+
+pub fn solve_with_details(instructions: &Vec<Instruction>) -> Result<(u64, Value), Error> {
+    let (sum, addresses) = execute(instructions);
+    return Ok((sum, json!({ "instructions": instructions.len(), "addresses": addresses })));
+}
+
+pub fn part2(input: &Input) -> Result<u64, Error> {
+    let instructions = parse_file(input)?;
+    return solve(&instructions);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(u64, Value), Error> {
+    let instructions = parse_file(input)?;
+    return solve_with_details(&instructions);
+}
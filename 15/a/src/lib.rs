@@ -0,0 +1,112 @@
+use std::error;
+use std::fmt;
+use std::collections::HashMap;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::trace;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    Missing { line: usize, expected: &'static str },
+    NoStartingNumbers,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::Missing { line, expected } => write!(f, "Line {} is missing, expected {}", line, expected),
+            Error::NoStartingNumbers => write!(f, "The game needs at least one starting number"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+// Which number spoken is the answer.
+pub const TURNS: usize = 2020;
+
+pub fn parse_file(input: &Input) -> Result<Vec<i64>, Error> {
+    let lines = aoc_common::read_lines(input)?;
+    let first_line = lines.first().ok_or(Error::Missing { line: 1, expected: "the starting numbers" })?;
+
+    let mut numbers = Vec::new();
+    for num in first_line.split(",") {
+        match num.parse::<i64>() {
+            Ok(number) if number >= 0 => numbers.push(number),
+            _ => return Err(Error::Malformed { line: 1, text: String::from(first_line), expected: "comma separated numbers" })
+        }
+    }
+
+    return Ok(numbers);
+}
+
+fn increment_turns(numbers: &mut HashMap<i64, i64>) {
+    for value in numbers.values_mut() {
+        *value += 1;
+    }
+}
+
+fn play_game(start_numbers: &Vec<i64>, rounds: usize) -> i64 {
+    let mut previous_numbers: HashMap<i64, i64> = HashMap::new();
+
+    for number in start_numbers {
+        increment_turns(&mut previous_numbers);
+        previous_numbers.entry(*number).or_insert(0);
+    }
+
+    let mut previous_number = *start_numbers.last().unwrap();
+    for _ in start_numbers.len()..rounds {
+        if previous_numbers.contains_key(&previous_number) {
+            let new_number = *previous_numbers.get(&previous_number).unwrap();
+            previous_numbers.insert(previous_number, 0);
+
+            trace!("Previous Number: {}, New Number: {}", previous_number, new_number);
+            previous_number = new_number;
+        } else {
+            previous_numbers.insert(previous_number, 0);
+            previous_number = 0;
+        }
+        increment_turns(&mut previous_numbers);
+    }
+
+    return previous_number;
+}
+
+pub fn solve(starting_numbers: &Vec<i64>) -> Result<i64, Error> {
+    return Ok(solve_with_details(starting_numbers)?.0);
+}
+
+pub fn solve_with_details(starting_numbers: &Vec<i64>) -> Result<(i64, Value), Error> {
+    if starting_numbers.is_empty() {
+        return Err(Error::NoStartingNumbers);
+    }
+
+    return Ok((play_game(starting_numbers, TURNS), json!({ "starting_numbers": starting_numbers, "turns": TURNS })));
+}
+
+pub fn part1(input: &Input) -> Result<i64, Error> {
+    let starting_numbers = parse_file(input)?;
+    return solve(&starting_numbers);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let starting_numbers = parse_file(input)?;
+    return solve_with_details(&starting_numbers);
+}
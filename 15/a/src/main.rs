@@ -1,58 +1,20 @@
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-use std::collections::HashMap;
+use std::time::Instant;
 
-fn parse_file(file_name: &Path) -> Vec<i64> {
-    let file = match File::open(&file_name) {
-        Err(why) => panic!("Couldn't open {}: {}", file_name.display(), why),
-        Ok(file) => file
-    };
-
-    let mut lines = io::BufReader::new(file).lines();
+use day15a::part1_with_details;
 
-    return lines.next().unwrap().unwrap().split(",").map(|num| num.parse::<i64>().unwrap()).collect();
-}
-
-fn increment_turns(numbers: &mut HashMap<i64, i64>) {
-    for value in numbers.values_mut() {
-        *value += 1;
-    }
-}
-
-fn play_game(start_numbers: &Vec<i64>, rounds: usize) -> i64 {
-    let mut previous_numbers: HashMap<i64, i64> = HashMap::new();
-
-    for number in start_numbers {
-        increment_turns(&mut previous_numbers);
-        previous_numbers.entry(*number).or_insert(0);
-    }
-
-    let mut previous_number = *start_numbers.last().unwrap();
-    for _ in start_numbers.len()..rounds {
-        if previous_numbers.contains_key(&previous_number) {
-            let new_number = *previous_numbers.get(&previous_number).unwrap();
-            previous_numbers.insert(previous_number, 0);
+fn main() {
+    let options = aoc_common::cli::parse_args();
 
-            //println!("Previous Number: {}, New Number: {}", previous_number, new_number);
-            previous_number = new_number;
-        } else {
-            previous_numbers.insert(previous_number, 0);
-            previous_number = 0;
-        }
-        increment_turns(&mut previous_numbers);
+    let start = Instant::now();
+    let (final_number, mut details) = match part1_with_details(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(found) => found
+    };
+    if options.json {
+        details["input"] = aoc_common::json!(options.input.to_string());
+        details["description"] = aoc_common::json!("2020th number spoken");
+        aoc_common::output::print_json(15, 1, &final_number, start.elapsed(), details);
+    } else {
+        println!("{}", final_number);
     }
-
-    return previous_number;
 }
-
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let input_file = Path::new(&args[1]);
-
-    let starting_numbers = parse_file(&input_file);
-    let final_number = play_game(&starting_numbers, 2020);
-    println!("{}", final_number);
-}
\ No newline at end of file
@@ -1,56 +1,20 @@
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-use std::collections::HashMap;
+use std::time::Instant;
 
-fn parse_file(file_name: &Path) -> Vec<i64> {
-    let file = match File::open(&file_name) {
-        Err(why) => panic!("Couldn't open {}: {}", file_name.display(), why),
-        Ok(file) => file
-    };
-
-    let mut lines = io::BufReader::new(file).lines();
-
-    return lines.next().unwrap().unwrap().split(",").map(|num| num.parse::<i64>().unwrap()).collect();
-}
-
-fn play_game(start_numbers: &Vec<i64>, rounds: i64) -> i64 {
-    let mut previous_numbers: HashMap<i64, i64> = HashMap::new();
-
-    let mut current_turn = 1;
-
-    for number in start_numbers {
-        previous_numbers.entry(*number).or_insert(current_turn);
-        current_turn += 1;
-    }
+use day15b::part2_with_details;
 
-    let mut previous_number = *start_numbers.last().unwrap();
-    for i in current_turn..(rounds + 1) {
-        if previous_numbers.contains_key(&previous_number) {
-            let previous_turn_spoken = *previous_numbers.get(&previous_number).unwrap();
-            previous_numbers.insert(previous_number, i - 1);
-            
-            previous_number = (i - 1) - previous_turn_spoken;
-        } else {
-            previous_numbers.insert(previous_number, i - 1);
-            previous_number = 0;
-        }
+fn main() {
+    let options = aoc_common::cli::parse_args();
 
-        if i % 1000000 == 0{
-            println!("i: {}", i / 1000000);
-        }
+    let start = Instant::now();
+    let (final_number, mut details) = match part2_with_details(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(found) => found
+    };
+    if options.json {
+        details["input"] = aoc_common::json!(options.input.to_string());
+        details["description"] = aoc_common::json!("30000000th number spoken");
+        aoc_common::output::print_json(15, 2, &final_number, start.elapsed(), details);
+    } else {
+        println!("{}", final_number);
     }
-
-    return previous_number;
 }
-
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let input_file = Path::new(&args[1]);
-
-    let starting_numbers = parse_file(&input_file);
-    let final_number = play_game(&starting_numbers, 30000000);
-    println!("{}", final_number);
-}
\ No newline at end of file
@@ -0,0 +1,158 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Record, Value};
+
+#[derive(Debug)]
+pub struct Rule {
+    valid_ranges: Vec<std::ops::Range<i64>>,
+}
+
+#[derive(Debug)]
+pub struct Ticket {
+    values: Vec<i64>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    MissingSection(&'static str),
+    WrongValueCount { line: usize, expected: usize, found: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::MissingSection(section) => write!(f, "The notes are missing the {} section", section),
+            Error::WrongValueCount { line, expected, found } => write!(f, "The ticket on line {} has {} values, expected one for each of the {} fields", line, found, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+const RULE_FORM: &str = "\"<field>: <min>-<max> or <min>-<max>\"";
+
+fn parse_range(s: &str) -> Option<std::ops::Range<i64>> {
+    let (start, end) = s.trim().split_once("-")?;
+
+    return Some(std::ops::Range {
+        start: start.parse::<i64>().ok()?,
+        end: end.parse::<i64>().ok()? + 1,
+    });
+}
+
+fn parse_rule(line_number: usize, line: &String) -> Result<Rule, Error> {
+    let malformed = || Error::Malformed { line: line_number, text: String::from(line), expected: RULE_FORM };
+
+    // Part 1 only needs the ranges, not which field they're for.
+    let (_, ranges) = line.split_once(":").ok_or_else(malformed)?;
+    let valid_ranges = ranges.split("or").map(|r| parse_range(r)).collect::<Option<_>>().ok_or_else(malformed)?;
+
+    return Ok(Rule {
+        valid_ranges: valid_ranges,
+    });
+}
+
+fn parse_ticket(line_number: usize, line: &String, field_count: usize) -> Result<Ticket, Error> {
+    let values: Vec<i64> = match line.split(",").map(|n| n.parse::<i64>()).collect() {
+        Err(_) => return Err(Error::Malformed { line: line_number, text: String::from(line), expected: "comma separated numbers" }),
+        Ok(values) => values
+    };
+
+    if values.len() != field_count {
+        return Err(Error::WrongValueCount { line: line_number, expected: field_count, found: values.len() });
+    }
+
+    return Ok(Ticket {
+        values: values,
+    });
+}
+
+// A titled section of the notes, like "your ticket:", followed by one ticket per line.
+fn parse_tickets(record: Option<&Record>, title: &'static str, field_count: usize) -> Result<Vec<Ticket>, Error> {
+    let record = record.ok_or(Error::MissingSection(title))?;
+    if record.lines[0] != title {
+        return Err(Error::Malformed { line: record.line, text: String::from(&record.lines[0]), expected: title });
+    }
+
+    let mut tickets = Vec::new();
+    for (offset, line) in record.lines.iter().enumerate().skip(1) {
+        tickets.push(parse_ticket(record.line + offset, line, field_count)?);
+    }
+
+    return Ok(tickets);
+}
+
+pub fn parse_file(input: &Input) -> Result<(Vec<Rule>, Ticket, Vec<Ticket>), Error> {
+    let records = aoc_common::read_records(input)?;
+
+    let rules_record = records.first().ok_or(Error::MissingSection("rules"))?;
+    let mut rules = Vec::new();
+    for (offset, line) in rules_record.lines.iter().enumerate() {
+        rules.push(parse_rule(rules_record.line + offset, line)?);
+    }
+
+    let your_tickets = parse_tickets(records.get(1), "your ticket:", rules.len())?;
+    let your_ticket = your_tickets.into_iter().next().ok_or(Error::MissingSection("your ticket"))?;
+    let other_tickets = parse_tickets(records.get(2), "nearby tickets:", rules.len())?;
+
+    return Ok((rules, your_ticket, other_tickets));
+}
+
+fn find_invalid_values(ticket: &Ticket, rules: &Vec<Rule>) -> Vec<i64> {
+    let mut invalid_values = Vec::new();
+    for value in &ticket.values {
+        if !rules
+            .iter()
+            .any(|rule| rule.valid_ranges.iter().any(|range| range.contains(&value)))
+        {
+            invalid_values.push(*value);
+        }
+    }
+
+    return invalid_values;
+}
+
+pub fn solve((rules, _your_ticket, nearby_tickets): &(Vec<Rule>, Ticket, Vec<Ticket>)) -> Result<i64, Error> {
+    return Ok(nearby_tickets
+        .iter()
+        .flat_map(|ticket| find_invalid_values(ticket, rules))
+        .sum());
+}
+
+// The error rate along with the values that make it up.
+pub fn solve_with_details((rules, _your_ticket, nearby_tickets): &(Vec<Rule>, Ticket, Vec<Ticket>)) -> Result<(i64, Value), Error> {
+    let invalid_values: Vec<i64> = nearby_tickets
+        .iter()
+        .flat_map(|ticket| find_invalid_values(ticket, rules))
+        .collect();
+
+    return Ok((invalid_values.iter().sum(), json!({ "nearby_tickets": nearby_tickets.len(), "invalid_values": invalid_values })));
+}
+
+pub fn part1(input: &Input) -> Result<i64, Error> {
+    let notes = parse_file(input)?;
+    return solve(&notes);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let notes = parse_file(input)?;
+    return solve_with_details(&notes);
+}
@@ -0,0 +1,218 @@
+use std::error;
+use std::fmt;
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{json, Input, InputError, Record, Value};
+use log::debug;
+
+#[derive(Debug)]
+pub struct Rule {
+    field_name: String,
+    valid_ranges: Vec<std::ops::Range<i64>>,
+}
+
+#[derive(Debug)]
+pub struct Ticket {
+    values: Vec<i64>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    MissingSection(&'static str),
+    WrongValueCount { line: usize, expected: usize, found: usize },
+    AmbiguousFields,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::MissingSection(section) => write!(f, "The notes are missing the {} section", section),
+            Error::WrongValueCount { line, expected, found } => write!(f, "The ticket on line {} has {} values, expected one for each of the {} fields", line, found, expected),
+            Error::AmbiguousFields => write!(f, "The tickets don't narrow every field down to a single position"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+const RULE_FORM: &str = "\"<field>: <min>-<max> or <min>-<max>\"";
+
+fn parse_range(s: &str) -> Option<std::ops::Range<i64>> {
+    let (start, end) = s.trim().split_once("-")?;
+
+    return Some(std::ops::Range {
+        start: start.parse::<i64>().ok()?,
+        end: end.parse::<i64>().ok()? + 1,
+    });
+}
+
+fn parse_rule(line_number: usize, line: &String) -> Result<Rule, Error> {
+    let malformed = || Error::Malformed { line: line_number, text: String::from(line), expected: RULE_FORM };
+
+    let (field_name, ranges) = line.split_once(":").ok_or_else(malformed)?;
+    let valid_ranges = ranges.split("or").map(|r| parse_range(r)).collect::<Option<_>>().ok_or_else(malformed)?;
+
+    return Ok(Rule {
+        field_name: String::from(field_name),
+        valid_ranges: valid_ranges,
+    });
+}
+
+fn parse_ticket(line_number: usize, line: &String, field_count: usize) -> Result<Ticket, Error> {
+    let values: Vec<i64> = match line.split(",").map(|n| n.parse::<i64>()).collect() {
+        Err(_) => return Err(Error::Malformed { line: line_number, text: String::from(line), expected: "comma separated numbers" }),
+        Ok(values) => values
+    };
+
+    if values.len() != field_count {
+        return Err(Error::WrongValueCount { line: line_number, expected: field_count, found: values.len() });
+    }
+
+    return Ok(Ticket {
+        values: values,
+    });
+}
+
+// A titled section of the notes, like "your ticket:", followed by one ticket per line.
+fn parse_tickets(record: Option<&Record>, title: &'static str, field_count: usize) -> Result<Vec<Ticket>, Error> {
+    let record = record.ok_or(Error::MissingSection(title))?;
+    if record.lines[0] != title {
+        return Err(Error::Malformed { line: record.line, text: String::from(&record.lines[0]), expected: title });
+    }
+
+    let mut tickets = Vec::new();
+    for (offset, line) in record.lines.iter().enumerate().skip(1) {
+        tickets.push(parse_ticket(record.line + offset, line, field_count)?);
+    }
+
+    return Ok(tickets);
+}
+
+pub fn parse_file(input: &Input) -> Result<(Vec<Rule>, Ticket, Vec<Ticket>), Error> {
+    let records = aoc_common::read_records(input)?;
+
+    let rules_record = records.first().ok_or(Error::MissingSection("rules"))?;
+    let mut rules = Vec::new();
+    for (offset, line) in rules_record.lines.iter().enumerate() {
+        rules.push(parse_rule(rules_record.line + offset, line)?);
+    }
+
+    let your_tickets = parse_tickets(records.get(1), "your ticket:", rules.len())?;
+    let your_ticket = your_tickets.into_iter().next().ok_or(Error::MissingSection("your ticket"))?;
+    let tickets = parse_tickets(records.get(2), "nearby tickets:", rules.len())?;
+
+    return Ok((rules, your_ticket, tickets));
+}
+
+fn is_valid_value(value: &i64, rule: &Rule) -> bool {
+    return rule.valid_ranges.iter().any(|range| range.contains(value));
+}
+
+fn is_valid_ticket(ticket: &Ticket, rules: &Vec<Rule>) -> bool {
+    for value in &ticket.values {
+        if !rules.iter().any(|rule| is_valid_value(value, rule)) {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+fn extract_sole_matches(matches: &mut HashMap<String, HashSet<usize>>) -> HashMap<String, usize> {
+    let mut sole_matches = HashMap::new();
+    for (key, value) in matches.iter() {
+        if value.len() == 1 {
+            sole_matches.insert(key.clone(), *value.iter().next().unwrap());
+        }
+    }
+
+    for (key, value) in sole_matches.iter() {
+        matches.remove(key);
+        for v in matches.values_mut() {
+            v.remove(value);
+        }
+    }
+
+    return sole_matches;
+}
+
+fn find_field_indices(tickets: &Vec<&Ticket>, rules: &Vec<Rule>) -> Result<HashMap<String, usize>, Error> {
+    let mut mapping = HashMap::new();
+    for i in 0..rules.len() {
+        for j in 0..rules.len() {
+            if tickets
+                .iter()
+                .all(|ticket| is_valid_value(&ticket.values[i], &rules[j]))
+            {
+                let matching_set = mapping.entry(rules[j].field_name.clone()).or_insert(HashSet::new());
+                matching_set.insert(i);
+            }
+        }
+    }
+
+    let mut complete_mapping = HashMap::new();
+    while !mapping.is_empty() {
+        let sole_matches = extract_sole_matches(&mut mapping);
+        if sole_matches.is_empty() {
+            return Err(Error::AmbiguousFields);
+        }
+
+        for (key, value) in sole_matches.iter() {
+            complete_mapping.insert(key.clone(), *value);
+        }
+    }
+
+    return Ok(complete_mapping);
+}
+
+pub fn solve(notes: &(Vec<Rule>, Ticket, Vec<Ticket>)) -> Result<i64, Error> {
+    return Ok(solve_with_details(notes)?.0);
+}
+
+// The product along with the name of the field in each position of a ticket.
+pub fn solve_with_details((rules, your_ticket, tickets): &(Vec<Rule>, Ticket, Vec<Ticket>)) -> Result<(i64, Value), Error> {
+    let valid_tickets: Vec<&Ticket> = tickets
+        .iter()
+        .filter(|ticket| is_valid_ticket(ticket, rules))
+        .collect();
+    let field_indices = find_field_indices(&valid_tickets, rules)?;
+
+    let mut n = 1;
+    let mut fields = vec![""; rules.len()];
+    for (key, value) in field_indices.iter() {
+        debug!("Key: {}", key);
+        if key.starts_with("departure") {
+            n *= your_ticket.values[*value];
+        }
+        fields[*value] = key;
+    }
+
+    return Ok((n, json!({ "valid_tickets": valid_tickets.len(), "fields": fields })));
+}
+
+pub fn part2(input: &Input) -> Result<i64, Error> {
+    let notes = parse_file(input)?;
+    return solve(&notes);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let notes = parse_file(input)?;
+    return solve_with_details(&notes);
+}
@@ -0,0 +1,208 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::{info, trace};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    EmptyMap,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::EmptyMap => write!(f, "The starting layer is empty"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_cube(cell: char) -> Option<char> {
+    return match cell {
+        '.' | '#' => Some(cell),
+        _ => None
+    };
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Vec<char>>, Error> {
+    return Ok(aoc_common::parse_grid(input, "'.' or '#'", parse_cube)?);
+}
+
+struct Change {
+    row: usize,
+    column: usize,
+    layer: usize,
+}
+
+fn count_active_around(map: &Vec<Vec<Vec<char>>>, row: i32, column: i32, layer: i32) -> usize {
+    let mut occupied = 0;
+
+    for i in -1..2 {
+        for j in -1..2 {
+            for k in -1..2 {
+                if j == 0 && i == 0 && k == 0 {
+                    continue;
+                }
+
+                let check_row = row + i;
+                let check_col = column + j;
+                let check_layer = layer + k;
+
+                if check_row < 0 || check_row >= map.len() as i32 {
+                    continue;
+                }
+
+                if check_col < 0 || check_col >= map[0].len() as i32 {
+                    continue;
+                }
+
+                if check_layer < 0 || check_layer >= map[0][0].len() as i32 {
+                    continue;
+                }
+
+                if map[check_row as usize][check_col as usize][check_layer as usize] == '#' {
+                    occupied += 1;
+                }
+            }
+        }
+    }
+
+    return occupied;
+}
+
+fn find_changes(map: &Vec<Vec<Vec<char>>>) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    for i in 0..map.len() {
+        for j in 0..map[0].len() {
+            for k in 0..map[0][0].len() {
+                let current_value = map[i as usize][j as usize][k as usize];
+                let occupied = count_active_around(map, i as i32, j as i32, k as i32);
+
+                let becomes_active = current_value == '.' && occupied == 3;
+                let becomes_inactive = current_value == '#' && !(occupied == 2 || occupied == 3);
+                if becomes_active || becomes_inactive {
+                    changes.push(Change{row: i, column: j, layer: k});
+                }
+            }
+        }
+    }
+
+    return changes;
+}
+
+fn apply_changes(map: &mut Vec<Vec<Vec<char>>>, changes: &Vec<Change>) {
+    for change in changes {
+        if map[change.row][change.column][change.layer] == '.' {
+            map[change.row][change.column][change.layer] = '#';
+        } else {
+            map[change.row][change.column][change.layer] = '.';
+        }
+    }
+}
+
+fn format_map(map: &Vec<Vec<Vec<char>>>, cycles: usize) -> String {
+    let mut formatted = String::new();
+    for height in 0..(cycles * 2 + 1) {
+        formatted += &format!("z={}\n", height as i32 - cycles as i32);
+        for row in map {
+            for col in row {
+                formatted.push(col[height]);
+            }
+            formatted.push('\n');
+        }
+        formatted.push('\n');
+    }
+
+    return formatted;
+}
+
+fn expand_map(start_layer: &Vec<Vec<char>>, cycles: usize) -> Vec<Vec<Vec<char>>> {
+    let max_width = start_layer.len() + cycles * 2;
+    let max_length = start_layer[0].len() + cycles * 2;
+    let max_height = cycles * 2 + 1;
+
+    let mut expanded_map = Vec::new();
+    for i in 0..max_width {
+        expanded_map.push(Vec::new());
+        for _ in 0..max_length {
+            expanded_map[i].push(vec!['.'; max_height]);
+        }
+    }
+
+    let z = cycles;
+    for x in cycles..(cycles + start_layer.len()) {
+        for y in cycles..(cycles + start_layer[0].len()) {
+            expanded_map[x][y][z] = start_layer[x - cycles][y - cycles];
+        }
+    }
+
+    return expanded_map;
+}
+
+fn count_total_active(map: &Vec<Vec<Vec<char>>>) -> i32{
+    let mut count = 0;
+    for row in map {
+        for col in row {
+            for layer in col {
+                if *layer == '#' {
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    return count;
+}
+
+pub fn solve(start_layer: &Vec<Vec<char>>) -> Result<i32, Error> {
+    return Ok(solve_with_details(start_layer)?.0);
+}
+
+// The active cubes along with how many cubes changed in each cycle.
+pub fn solve_with_details(start_layer: &Vec<Vec<char>>) -> Result<(i32, Value), Error> {
+    if start_layer.is_empty() || start_layer[0].is_empty() {
+        return Err(Error::EmptyMap);
+    }
+
+    let mut map = expand_map(start_layer, 6);
+    trace!("Start:\n{}", format_map(&map, 6));
+
+    let mut changed = Vec::new();
+    for i in 0..6 {
+        let changes = find_changes(&map);
+        apply_changes(&mut map, &changes);
+        changed.push(changes.len());
+        info!("Iteration {}: {} changes", i + 1, changes.len());
+        trace!("After iteration {}:\n{}", i + 1, format_map(&map, 6));
+    }
+
+    return Ok((count_total_active(&map), json!({ "dimensions": 3, "changes": changed })));
+}
+
+pub fn part1(input: &Input) -> Result<i32, Error> {
+    let start_layer = parse_file(input)?;
+    return solve(&start_layer);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i32, Value), Error> {
+    let start_layer = parse_file(input)?;
+    return solve_with_details(&start_layer);
+}
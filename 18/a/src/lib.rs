@@ -0,0 +1,157 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::trace;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    Overflow { line: usize },
+    Unexpected { line: usize, expected: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::Overflow { line } => write!(f, "The expression on line {} doesn't fit in a 64 bit number", line),
+            Error::Unexpected { line, expected } => write!(f, "Couldn't evaluate line {}: expected {}", line, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Token {
+    Open,
+    Close,
+    Add,
+    Multiply,
+    Number(i64),
+}
+
+// One line of homework, already checked to be a well formed expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    pub line: usize,
+    pub tokens: Vec<Token>,
+}
+
+fn tokenize(line: &str) -> Option<Vec<Token>> {
+    let expanded = line.replace("(", "( ").replace(")", " )");
+    return expanded.split_whitespace().map(|token| match token {
+        "(" => Some(Token::Open),
+        ")" => Some(Token::Close),
+        "+" => Some(Token::Add),
+        "*" => Some(Token::Multiply),
+        number => number.parse::<i64>().ok().map(Token::Number),
+    }).collect();
+}
+
+// Numbers joined by + or *, with balanced parentheses.
+fn is_valid_expression(tokens: &[Token]) -> bool {
+    let mut expect_operand = true;
+    let mut depth = 0;
+
+    for token in tokens {
+        match (expect_operand, token) {
+            (true, Token::Open) => depth += 1,
+            (false, Token::Close) if depth > 0 => depth -= 1,
+            (false, Token::Add) | (false, Token::Multiply) => expect_operand = true,
+            (true, Token::Number(_)) => expect_operand = false,
+            _ => return false,
+        }
+    }
+
+    return !expect_operand && depth == 0;
+}
+
+fn break_expression_line(line_number: usize, line: &str) -> Result<Expression, Error> {
+    let malformed = || Error::Malformed { line: line_number, text: String::from(line), expected: "numbers joined by + or * with balanced parentheses" };
+    let tokens = tokenize(line).ok_or_else(malformed)?;
+    if !is_valid_expression(&tokens) {
+        return Err(malformed());
+    }
+
+    return Ok(Expression { line: line_number, tokens: tokens });
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Expression>, Error> {
+    return aoc_common::parse_lines_with(input, break_expression_line);
+}
+
+// Evaluates left to right up to the end of the innermost parentheses it's in, and gives back what follows them.
+fn parse_expression(line: usize, expression: &[Token]) -> Result<(i64, &[Token]), Error> {
+    trace!("Expression: {:?}", expression);
+    let (mut left, mut current_expression) = parse_value(line, expression)?;
+    loop {
+        let operator = match current_expression.first() {
+            None => return Ok((left, current_expression)),
+            Some(Token::Close) => return Ok((left, &current_expression[1..])),
+            Some(operator) => *operator,
+        };
+
+        let (right, next_expr) = parse_value(line, &current_expression[1..])?;
+        trace!("Left: {}, Right: {}, Op: {:?}", left, right, operator);
+        let result = match operator {
+            Token::Multiply => left.checked_mul(right),
+            Token::Add => left.checked_add(right),
+            _ => return Err(Error::Unexpected { line: line, expected: "+, * or )" }),
+        };
+        left = result.ok_or(Error::Overflow { line: line })?;
+        current_expression = next_expr;
+    }
+}
+
+fn parse_value(line: usize, expression: &[Token]) -> Result<(i64, &[Token]), Error> {
+    trace!("Value: {:?}", expression);
+    return match expression.first() {
+        Some(Token::Open) => parse_expression(line, &expression[1..]),
+        Some(Token::Number(value)) => Ok((*value, &expression[1..])),
+        _ => Err(Error::Unexpected { line: line, expected: "a number or (" }),
+    };
+}
+
+pub fn solve(expressions: &Vec<Expression>) -> Result<i64, Error> {
+    return Ok(solve_with_details(expressions)?.0);
+}
+
+// The sum along with the value of each line.
+pub fn solve_with_details(expressions: &Vec<Expression>) -> Result<(i64, Value), Error> {
+    let mut sum: i64 = 0;
+    let mut values = Vec::with_capacity(expressions.len());
+    for expression in expressions {
+        let (result, _) = parse_expression(expression.line, &expression.tokens)?;
+        sum = sum.checked_add(result).ok_or(Error::Overflow { line: expression.line })?;
+        values.push(json!({ "line": expression.line, "value": result }));
+    }
+
+    return Ok((sum, json!({ "values": values })));
+}
+
+pub fn part1(input: &Input) -> Result<i64, Error> {
+    let expressions = parse_file(input)?;
+    return solve(&expressions);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let expressions = parse_file(input)?;
+    return solve_with_details(&expressions);
+}
@@ -0,0 +1,186 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::{debug, trace};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    Overflow { line: usize },
+    Unexpected { line: usize, expected: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::Overflow { line } => write!(f, "The expression on line {} doesn't fit in a 64 bit number", line),
+            Error::Unexpected { line, expected } => write!(f, "Couldn't evaluate line {}: expected {}", line, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Token {
+    Open,
+    Close,
+    Add,
+    Multiply,
+    Number(i64),
+}
+
+// One line of homework, already checked to be a well formed expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    pub line: usize,
+    pub tokens: Vec<Token>,
+}
+
+fn tokenize(line: &str) -> Option<Vec<Token>> {
+    let expanded = line.replace("(", "( ").replace(")", " )");
+    return expanded.split_whitespace().map(|token| match token {
+        "(" => Some(Token::Open),
+        ")" => Some(Token::Close),
+        "+" => Some(Token::Add),
+        "*" => Some(Token::Multiply),
+        number => number.parse::<i64>().ok().map(Token::Number),
+    }).collect();
+}
+
+// Numbers joined by + or *, with balanced parentheses.
+fn is_valid_expression(tokens: &[Token]) -> bool {
+    let mut expect_operand = true;
+    let mut depth = 0;
+
+    for token in tokens {
+        match (expect_operand, token) {
+            (true, Token::Open) => depth += 1,
+            (false, Token::Close) if depth > 0 => depth -= 1,
+            (false, Token::Add) | (false, Token::Multiply) => expect_operand = true,
+            (true, Token::Number(_)) => expect_operand = false,
+            _ => return false,
+        }
+    }
+
+    return !expect_operand && depth == 0;
+}
+
+fn break_expression_line(line_number: usize, line: &str) -> Result<Expression, Error> {
+    let malformed = || Error::Malformed { line: line_number, text: String::from(line), expected: "numbers joined by + or * with balanced parentheses" };
+    let mut tokens = tokenize(line).ok_or_else(malformed)?;
+    if !is_valid_expression(&tokens) {
+        return Err(malformed());
+    }
+
+    let mut full = vec![Token::Open];
+    full.append(&mut tokens);
+    full.push(Token::Close);
+
+    return Ok(Expression { line: line_number, tokens: full });
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Expression>, Error> {
+    return aoc_common::parse_lines_with(input, break_expression_line);
+}
+
+fn parse_multiplication(line: usize, expression: &[Token]) -> Result<(i64, &[Token]), Error> {
+    let (mut left, mut remaining) = parse_addition(line, expression)?;
+    while remaining.first() == Some(&Token::Multiply) {
+        let (right, remaining2) = parse_addition(line, &remaining[1..])?;
+        trace!("{} * {}", left, right);
+        left = left.checked_mul(right).ok_or(Error::Overflow { line: line })?;
+        remaining = remaining2;
+    }
+
+    return Ok((left, remaining));
+}
+
+fn parse_addition(line: usize, expression: &[Token]) -> Result<(i64, &[Token]), Error> {
+    let (mut left, mut remaining) = parse_value(line, expression)?;
+
+    while remaining.first() == Some(&Token::Add) {
+        let (right, remaining2) = parse_value(line, &remaining[1..])?;
+        trace!("{} + {}", left, right);
+        left = left.checked_add(right).ok_or(Error::Overflow { line: line })?;
+        remaining = remaining2;
+    }
+
+    return Ok((left, remaining));
+}
+
+// Evaluates the parenthesised expression at the start, and gives back what follows it.
+fn parse_expression(line: usize, expression: &[Token]) -> Result<(i64, &[Token]), Error> {
+    if expression.first() != Some(&Token::Open) {
+        return Err(Error::Unexpected { line: line, expected: "(" });
+    }
+
+    trace!("Expression: {:?}", expression);
+    let (value, current_expression) = parse_multiplication(line, &expression[1..])?;
+
+    trace!("expression: {:?}", current_expression);
+    if current_expression.first() != Some(&Token::Close) {
+        return Err(Error::Unexpected { line: line, expected: ")" });
+    }
+
+    return Ok((value, &current_expression[1..]));
+}
+
+fn parse_value(line: usize, expression: &[Token]) -> Result<(i64, &[Token]), Error> {
+    let (value, rest) = match expression.first() {
+        Some(Token::Open) => parse_expression(line, expression)?,
+        Some(Token::Number(value)) => (*value, &expression[1..]),
+        _ => return Err(Error::Unexpected { line: line, expected: "a number or (" }),
+    };
+
+    trace!("Value: {}", value);
+    return Ok((value, rest));
+}
+
+pub fn solve(expressions: &Vec<Expression>) -> Result<i64, Error> {
+    return Ok(solve_with_details(expressions)?.0);
+}
+
+// The sum along with the value of each line.
+pub fn solve_with_details(expressions: &Vec<Expression>) -> Result<(i64, Value), Error> {
+    let mut results = Vec::with_capacity(expressions.len());
+    for expression in expressions {
+        results.push(parse_expression(expression.line, &expression.tokens)?.0);
+    }
+    debug!("Results: {:?}", results);
+
+    let mut sum: i64 = 0;
+    for (expression, result) in expressions.iter().zip(&results) {
+        sum = sum.checked_add(*result).ok_or(Error::Overflow { line: expression.line })?;
+    }
+
+    let values: Vec<Value> = expressions.iter().zip(&results).map(|(expression, result)| json!({ "line": expression.line, "value": result })).collect();
+    return Ok((sum, json!({ "values": values })));
+}
+
+pub fn part2(input: &Input) -> Result<i64, Error> {
+    let expressions = parse_file(input)?;
+    return solve(&expressions);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let expressions = parse_file(input)?;
+    return solve_with_details(&expressions);
+}
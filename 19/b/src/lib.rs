@@ -0,0 +1,181 @@
+use std::error;
+use std::fmt;
+use std::collections::HashMap;
+
+use aoc_common::{json, Input, InputError, Value};
+use regex::Regex;
+use log::{debug, trace};
+
+enum Rule {
+    Matches(Vec<Vec<usize>>),
+    Atom(char),
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    MissingSection(&'static str),
+    UndefinedRule(usize),
+    RecursiveRule(usize),
+    Regex(regex::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::MissingSection(section) => write!(f, "The input is missing the {} section", section),
+            Error::UndefinedRule(rule) => write!(f, "Rule {} is used but never defined", rule),
+            Error::RecursiveRule(rule) => write!(f, "Rule {} refers back to itself", rule),
+            Error::Regex(why) => write!(f, "Couldn't build the rule regex: {}", why),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            Error::Regex(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+const RULE_FORM: &str = "\"<number>: <rule numbers, with alternatives separated by |>\" or \"<number>: \"<letter>\"\"";
+
+fn parse_rule_part(part: &str) -> Option<Vec<usize>> {
+    return part.split(" ").filter(|p| *p != "").map(|p| p.parse::<usize>().ok()).collect();
+}
+
+fn parse_rule(line: &str) -> Option<Rule> {
+    if line.starts_with("\"") {
+        // Atoms go into the regex as they are, so only allow plain letters and digits.
+        let mut atom = line.trim_matches('"').chars();
+        return match (atom.next(), atom.next()) {
+            (Some(letter), None) if letter.is_ascii_alphanumeric() => Some(Rule::Atom(letter)),
+            _ => None,
+        };
+    }
+
+    let parts = line.split("|");
+
+    return Some(Rule::Matches(parts.map(|p| parse_rule_part(p)).collect::<Option<_>>()?));
+}
+
+fn make_regex_helper(current_rule_index: usize, rules: &HashMap<usize, Rule>, result: &mut Vec<char>, active_rules: &mut Vec<usize>) -> Result<(), Error> {
+    if active_rules.contains(&current_rule_index) {
+        return Err(Error::RecursiveRule(current_rule_index));
+    }
+    active_rules.push(current_rule_index);
+
+    match current_rule_index {
+        8 => {
+            result.push('(');
+            make_regex_helper(42, rules, result, active_rules)?;
+            result.push('+');
+            result.push(')');
+        },
+        11 => {
+            result.push('(');
+            make_regex_helper(42, rules, result, active_rules)?;
+            result.append(&mut "{%}".chars().collect());
+            make_regex_helper(31, rules, result, active_rules)?;
+            result.append(&mut "{%}".chars().collect());
+            result.push(')');
+        },
+        _ => {
+            let current_rule = rules.get(&current_rule_index).ok_or(Error::UndefinedRule(current_rule_index))?;
+
+            match current_rule {
+                Rule::Atom(s) => result.push(*s),
+                Rule::Matches(matches) => {
+                    result.push('(');
+                    for (index, m) in matches.iter().enumerate() {
+                        if index > 0 {
+                            result.push('|');
+                        }
+                        for s in m {
+                            make_regex_helper(*s, rules, result, active_rules)?;
+                        }
+                    }
+                    result.push(')');
+                }
+            }
+        }
+    };
+
+    active_rules.pop();
+    return Ok(());
+}
+
+fn make_regex(rules: &HashMap<usize, Rule>) -> Result<String, Error> {
+    let mut s = Vec::new();
+    s.push('^');
+    make_regex_helper(0, rules, &mut s, &mut Vec::new())?;
+    s.push('$');
+
+    let st: String = s.iter().collect();
+    debug!("Regex: {}", st);
+    return Ok(st);
+}
+
+pub fn parse_file(input: &Input) -> Result<(String, Vec<String>), Error> {
+    let records = aoc_common::read_records(input)?;
+    let rules_record = records.first().ok_or(Error::MissingSection("rules"))?;
+    let messages_record = records.get(1).ok_or(Error::MissingSection("messages"))?;
+
+    let mut rules = HashMap::new();
+    for (offset, line) in rules_record.lines.iter().enumerate() {
+        let malformed = || Error::Malformed { line: rules_record.line + offset, text: String::from(line), expected: RULE_FORM };
+
+        let (num, rest) = line.trim().split_once(':').ok_or_else(malformed)?;
+        let num = num.parse::<usize>().map_err(|_| malformed())?;
+        rules.insert(num, parse_rule(rest.trim()).ok_or_else(malformed)?);
+    }
+
+    let messages = messages_record.lines.iter().map(|line| String::from(line.trim())).collect();
+
+    return Ok((make_regex(&rules)?, messages));
+}
+
+pub fn solve(rules_and_messages: &(String, Vec<String>)) -> Result<usize, Error> {
+    return Ok(solve_with_details(rules_and_messages)?.0);
+}
+
+// The matching messages along with how many matched with each number of times around the looping rules.
+pub fn solve_with_details((r, messages): &(String, Vec<String>)) -> Result<(usize, Value), Error> {
+    let mut matches = 0;
+    let mut current_messages: Vec<&String> = messages.iter().collect();
+    let mut by_loops = Vec::new();
+
+    for i in 1..100 {
+        let regex = Regex::new(&r.replace("%", &i.to_string())).map_err(Error::Regex)?;
+        let matching = current_messages.iter().filter(|m| regex.is_match(m)).count();
+
+        matches += matching;
+        by_loops.push(matching);
+        current_messages = current_messages.iter().filter(|m| !regex.is_match(m)).copied().collect();
+        trace!("Matching: {} ({})", matches, i);
+    }
+
+    return Ok((matches, json!({ "messages": messages.len(), "matching_by_loops": by_loops })));
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let rules_and_messages = parse_file(input)?;
+    return solve(&rules_and_messages);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let rules_and_messages = parse_file(input)?;
+    return solve_with_details(&rules_and_messages);
+}
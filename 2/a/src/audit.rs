@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+
+use aoc_common::output::csv_row;
+use aoc_common::{json, Input};
+use serde_json::Value;
+
+use crate::policy::Interpretation;
+use crate::{parse_file_with, parse_password, Error, Password};
+
+// What went wrong across the whole database when it's read with one interpretation.
+#[derive(Debug, Default, PartialEq)]
+pub struct Tally {
+    pub valid: usize,
+    // Broken clauses, by the letter they're about. Clauses that aren't about a letter aren't counted.
+    pub failures_by_letter: BTreeMap<char, usize>,
+    // Broken clauses, by the keyword of the clause.
+    pub failures_by_clause: BTreeMap<&'static str, usize>,
+    // How many broken clauses were out of their range by each amount, for clauses that allow a range.
+    pub out_of_range: BTreeMap<usize, usize>,
+    // Lines that can't be read this way, like "0-3 a" as positions, with why.
+    pub unreadable: Vec<(usize, String)>,
+}
+
+impl Tally {
+    fn of<'a>(passwords: impl Iterator<Item = &'a Password>) -> Tally {
+        let mut tally = Tally::default();
+        for password in passwords {
+            let mut valid = true;
+            for clause in &password.policy {
+                if clause.violation(&password.value).is_none() {
+                    continue;
+                }
+
+                valid = false;
+                *tally.failures_by_clause.entry(clause.kind()).or_insert(0) += 1;
+                if let Some(letter) = clause.letter() {
+                    *tally.failures_by_letter.entry(letter).or_insert(0) += 1;
+                }
+                if let Some(distance) = clause.out_of_range(&password.value) {
+                    *tally.out_of_range.entry(distance).or_insert(0) += 1;
+                }
+            }
+
+            if valid {
+                tally.valid += 1;
+            }
+        }
+
+        return tally;
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Audit {
+    pub passwords: usize,
+    pub count: Tally,
+    pub positions: Tally,
+    // Passwords (line and value) that are valid when the puzzle's clauses are counts but not when they're positions...
+    pub only_valid_by_count: Vec<(usize, String)>,
+    // ... and the other way around.
+    pub only_valid_by_positions: Vec<(usize, String)>,
+}
+
+// Audits the same database read both ways, so the two lists need to be in the same order. Every line reads as counts,
+// but not every line reads as positions, and the ones that don't are only listed as unreadable.
+pub fn audit(by_count: &Vec<Password>, by_positions: &Vec<Result<Password, Error>>) -> Audit {
+    let mut only_valid_by_count = Vec::new();
+    let mut only_valid_by_positions = Vec::new();
+    let mut unreadable = Vec::new();
+    for (count, positions) in by_count.iter().zip(by_positions) {
+        let positions = match positions {
+            Err(why) => {
+                unreadable.push((count.line, why.to_string()));
+                continue;
+            },
+            Ok(positions) => positions
+        };
+        match (count.is_valid(), positions.is_valid()) {
+            (true, false) => only_valid_by_count.push((count.line, count.value.clone())),
+            (false, true) => only_valid_by_positions.push((positions.line, positions.value.clone())),
+            _ => (),
+        }
+    }
+
+    let mut positions = Tally::of(by_positions.iter().flatten());
+    positions.unreadable = unreadable;
+    return Audit {
+        passwords: by_count.len(),
+        count: Tally::of(by_count.iter()),
+        positions: positions,
+        only_valid_by_count: only_valid_by_count,
+        only_valid_by_positions: only_valid_by_positions,
+    };
+}
+
+pub fn audit_file(input: &Input) -> Result<Audit, Error> {
+    let by_count = parse_file_with(input, Interpretation::Count)?;
+    let lines = aoc_common::read_lines(input)?;
+    let by_positions = lines.iter().enumerate().map(|(index, line)| parse_password(index + 1, line, Interpretation::Positions)).collect();
+    return Ok(audit(&by_count, &by_positions));
+}
+
+fn tally_json(tally: &Tally) -> Value {
+    let by_letter: serde_json::Map<String, Value> = tally.failures_by_letter.iter().map(|(letter, count)| (letter.to_string(), json!(count))).collect();
+    let by_clause: serde_json::Map<String, Value> = tally.failures_by_clause.iter().map(|(kind, count)| (kind.to_string(), json!(count))).collect();
+    let out_of_range: serde_json::Map<String, Value> = tally.out_of_range.iter().map(|(distance, count)| (distance.to_string(), json!(count))).collect();
+
+    return json!({
+        "valid": tally.valid,
+        "failures_by_letter": by_letter,
+        "failures_by_clause": by_clause,
+        "out_of_range": out_of_range,
+        "unreadable": tally.unreadable.iter().map(|(line, why)| json!({ "line": line, "reason": why })).collect::<Vec<Value>>(),
+    });
+}
+
+fn passwords_json(passwords: &Vec<(usize, String)>) -> Value {
+    return passwords.iter().map(|(line, value)| json!({ "line": line, "password": value })).collect();
+}
+
+impl Audit {
+    pub fn to_json(&self) -> Value {
+        return json!({
+            "passwords": self.passwords,
+            "count": tally_json(&self.count),
+            "positions": tally_json(&self.positions),
+            "only_valid_by_count": passwords_json(&self.only_valid_by_count),
+            "only_valid_by_positions": passwords_json(&self.only_valid_by_positions),
+        });
+    }
+
+    // One statistic per row, as "<statistic>,<policy>,<key>,<value>".
+    pub fn to_csv(&self) -> String {
+        let mut rows = vec![
+            csv_row(&[String::from("statistic"), String::from("policy"), String::from("key"), String::from("value")]),
+            csv_row(&[String::from("passwords"), String::new(), String::new(), self.passwords.to_string()]),
+        ];
+
+        for (interpretation, tally) in &[(Interpretation::Count, &self.count), (Interpretation::Positions, &self.positions)] {
+            let policy = interpretation.to_string();
+            rows.push(csv_row(&[String::from("valid"), policy.clone(), String::new(), tally.valid.to_string()]));
+            for (letter, count) in &tally.failures_by_letter {
+                rows.push(csv_row(&[String::from("failures_by_letter"), policy.clone(), letter.to_string(), count.to_string()]));
+            }
+            for (kind, count) in &tally.failures_by_clause {
+                rows.push(csv_row(&[String::from("failures_by_clause"), policy.clone(), kind.to_string(), count.to_string()]));
+            }
+            for (distance, count) in &tally.out_of_range {
+                rows.push(csv_row(&[String::from("out_of_range"), policy.clone(), distance.to_string(), count.to_string()]));
+            }
+            for (line, why) in &tally.unreadable {
+                rows.push(csv_row(&[String::from("unreadable"), policy.clone(), line.to_string(), why.clone()]));
+            }
+        }
+
+        for (policy, passwords) in &[("count", &self.only_valid_by_count), ("positions", &self.only_valid_by_positions)] {
+            for (line, value) in passwords.iter() {
+                rows.push(csv_row(&[String::from("only_valid_by"), policy.to_string(), line.to_string(), value.clone()]));
+            }
+        }
+
+        return rows.join("\n") + "\n";
+    }
+}
@@ -0,0 +1,62 @@
+use std::time::Instant;
+
+use aoc_common::cli::{DayOption, Options};
+use aoc_common::json;
+
+use crate::audit;
+use crate::policy::Interpretation;
+use crate::{parse_file_with, solve_with_details};
+
+pub const OPTIONS: &[DayOption] = &[
+    DayOption { name: "policy", value: Some("<count|positions>"), description: "Read \"<a>-<b> <letter>\" as a count range (part 1) or two positions (part 2)" },
+    DayOption { name: "audit", value: Some("<csv|json>"), description: "Print failure statistics for the whole database under both policies instead" },
+];
+
+fn print_audit(options: &Options, format: &str) {
+    if format != "csv" && format != "json" {
+        aoc_common::exit_with_error(format!("Unknown audit format '{}', expected csv or json", format));
+    }
+
+    let audit = match audit::audit_file(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(audit) => audit
+    };
+    if format == "csv" {
+        print!("{}", audit.to_csv());
+    } else {
+        println!("{}", audit.to_json());
+    }
+}
+
+pub fn main(part: usize, default_interpretation: Interpretation) {
+    let options = aoc_common::cli::parse_args_with(OPTIONS);
+    if let Some(format) = options.value("audit") {
+        print_audit(&options, format);
+        return;
+    }
+
+    let interpretation = match options.value("policy") {
+        None => default_interpretation,
+        Some(name) => match Interpretation::from_name(name) {
+            None => aoc_common::exit_with_error(format!("Unknown policy '{}', expected count or positions", name)),
+            Some(interpretation) => interpretation,
+        },
+    };
+
+    let start = Instant::now();
+    let passwords = match parse_file_with(&options.input, interpretation) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(passwords) => passwords
+    };
+    let (valid_password_count, mut details) = match solve_with_details(&passwords) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(valid_password_count) => valid_password_count
+    };
+    if options.json {
+        details["input"] = json!(options.input.to_string());
+        details["description"] = json!(format!("Valid passwords under the {} policy", interpretation));
+        aoc_common::output::print_json(2, part, &valid_password_count, start.elapsed(), details);
+    } else {
+        print!("Valid passwords: {}", valid_password_count);
+    }
+}
@@ -0,0 +1,143 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::info;
+
+pub mod audit;
+pub mod command;
+pub mod policy;
+
+use policy::{Interpretation, PasswordPolicy, Violation};
+
+pub struct Password {
+    pub line: usize,
+    policy: Vec<Box<dyn PasswordPolicy>>,
+    pub value: String,
+}
+
+impl Password {
+    // Every clause of the policy the password breaks, in the order they were given.
+    pub fn violations(&self) -> Vec<Violation> {
+        return self.policy
+            .iter()
+            .filter_map(|clause| clause.violation(&self.value).map(|reason| Violation { clause: clause.to_string(), reason: reason }))
+            .collect();
+    }
+
+    pub fn is_valid(&self) -> bool {
+        return self.policy.iter().all(|clause| clause.violation(&self.value).is_none());
+    }
+
+    // The policy back in the syntax of the input, with each clause spelled out.
+    pub fn policy(&self) -> String {
+        return self.policy.iter().map(|clause| clause.to_string()).collect::<Vec<String>>().join("; ");
+    }
+}
+
+// A password that was turned down, with every clause of its policy that it broke.
+pub struct Rejection<'a> {
+    pub password: &'a Password,
+    pub violations: Vec<Violation>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+// The policy is one or more clauses separated by "; ", see `policy::parse_clause`.
+const PASSWORD_FORM: &str = "\"<policy>: <password>\"";
+
+fn parse_password(line_number: usize, line: &str, interpretation: Interpretation) -> Result<Password, Error> {
+    let malformed = |expected| Error::Malformed { line: line_number, text: String::from(line), expected: expected };
+
+    let (policy, value) = match line.rsplit_once(": ") {
+        Some((policy, value)) if !value.is_empty() => (policy, value),
+        _ => return Err(malformed(PASSWORD_FORM)),
+    };
+
+    let mut clauses = Vec::new();
+    for clause in policy.split("; ") {
+        clauses.push(policy::parse_clause(clause, interpretation).map_err(malformed)?);
+    }
+
+    return Ok(Password { line: line_number, policy: clauses, value: String::from(value) });
+}
+
+pub fn parse_file_with(input: &Input, interpretation: Interpretation) -> Result<Vec<Password>, Error> {
+    return aoc_common::parse_lines_with(input, |line_number, line| parse_password(line_number, line, interpretation));
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Password>, Error> {
+    return parse_file_with(input, Interpretation::Count);
+}
+
+// Every password that breaks its policy, in the order of the database.
+pub fn rejections(passwords: &Vec<Password>) -> Vec<Rejection<'_>> {
+    return passwords
+        .iter()
+        .map(|password| Rejection { password: password, violations: password.violations() })
+        .filter(|rejection| !rejection.violations.is_empty())
+        .collect();
+}
+
+pub fn solve(passwords: &Vec<Password>) -> Result<usize, Error> {
+    let rejected = rejections(passwords);
+    for rejection in &rejected {
+        let password = rejection.password;
+        info!("Line {}: \"{}\" breaks {}", password.line, password.value, rejection.violations.iter().map(|violation| violation.to_string()).collect::<Vec<String>>().join(", "));
+    }
+
+    return Ok(passwords.len() - rejected.len());
+}
+
+fn rejection_json(rejection: &Rejection) -> Value {
+    let violations: Vec<Value> = rejection.violations.iter().map(|violation| json!({ "clause": violation.clause, "reason": violation.reason })).collect();
+    return json!({ "line": rejection.password.line, "password": rejection.password.value, "violations": violations });
+}
+
+// The count along with every rejected password and why it was turned down.
+pub fn solve_with_details(passwords: &Vec<Password>) -> Result<(usize, Value), Error> {
+    let rejected: Vec<Value> = rejections(passwords).iter().map(rejection_json).collect();
+    return Ok((solve(passwords)?, json!({ "rejected": rejected })));
+}
+
+pub fn count_valid(input: &Input, interpretation: Interpretation) -> Result<usize, Error> {
+    let passwords = parse_file_with(input, interpretation)?;
+    return solve(&passwords);
+}
+
+pub fn part1(input: &Input) -> Result<usize, Error> {
+    return count_valid(input, Interpretation::Count);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let passwords = parse_file(input)?;
+    return solve_with_details(&passwords);
+}
@@ -0,0 +1,273 @@
+use std::fmt;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref RANGE_REGEX: Regex = Regex::new(r"^(?P<first>\d+)-(?P<second>\d+) (?P<letter>.)$").unwrap();
+}
+
+const COUNT_FORM: &str = "\"<min>-<max> <letter>\"";
+const POSITIONS_FORM: &str = "\"<first>-<second> <letter>\" with positions starting at 1";
+
+// Why a password was turned down: the clause of its policy that it broke, and how.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub clause: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{} ({})", self.clause, self.reason);
+    }
+}
+
+// One clause of a password's policy. Displaying it gives the clause back in the syntax of the policy line.
+pub trait PasswordPolicy: fmt::Display {
+    // The keyword the clause starts with.
+    fn kind(&self) -> &'static str;
+
+    // Why `password` breaks the clause, or None if it keeps to it.
+    fn violation(&self, password: &str) -> Option<String>;
+
+    // The letter the clause is about, for clauses about a single letter.
+    fn letter(&self) -> Option<char> {
+        return None;
+    }
+
+    // How far `password` is outside the range the clause allows (0 when it's inside), for clauses that allow a range.
+    fn out_of_range(&self, _password: &str) -> Option<usize> {
+        return None;
+    }
+}
+
+// The letter has to appear between `min` and `max` times (part 1).
+pub struct CountRange {
+    pub letter: char,
+    pub min: usize,
+    pub max: usize,
+}
+
+impl fmt::Display for CountRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{} {}-{} {}", self.kind(), self.min, self.max, self.letter);
+    }
+}
+
+impl PasswordPolicy for CountRange {
+    fn kind(&self) -> &'static str {
+        return "count";
+    }
+
+    fn violation(&self, password: &str) -> Option<String> {
+        let count = password.chars().filter(|character| *character == self.letter).count();
+        if count >= self.min && count <= self.max {
+            return None;
+        }
+
+        return Some(format!("'{}' appears {} times, expected {} to {}", self.letter, count, self.min, self.max));
+    }
+
+    fn letter(&self) -> Option<char> {
+        return Some(self.letter);
+    }
+
+    fn out_of_range(&self, password: &str) -> Option<usize> {
+        let count = password.chars().filter(|character| *character == self.letter).count();
+        return Some(self.min.saturating_sub(count) + count.saturating_sub(self.max));
+    }
+}
+
+// The letter has to be at exactly one of the two positions, counting from 1 (part 2).
+pub struct PositionalXor {
+    pub letter: char,
+    pub first: usize,
+    pub second: usize,
+}
+
+impl fmt::Display for PositionalXor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{} {}-{} {}", self.kind(), self.first, self.second, self.letter);
+    }
+}
+
+impl PasswordPolicy for PositionalXor {
+    fn kind(&self) -> &'static str {
+        return "positions";
+    }
+
+    fn violation(&self, password: &str) -> Option<String> {
+        // A position past the end of the password can't hold the letter.
+        let at_first = password.chars().nth(self.first - 1) == Some(self.letter);
+        let at_second = password.chars().nth(self.second - 1) == Some(self.letter);
+
+        return match (at_first, at_second) {
+            (true, true) => Some(format!("'{}' is at both positions {} and {}", self.letter, self.first, self.second)),
+            (false, false) => Some(format!("'{}' is at neither position {} nor {}", self.letter, self.first, self.second)),
+            _ => None,
+        };
+    }
+
+    fn letter(&self) -> Option<char> {
+        return Some(self.letter);
+    }
+}
+
+// The whole password has to match the pattern, as if it were wrapped in ^ and $.
+pub struct RegexMatch {
+    pub pattern: String,
+    regex: Regex,
+}
+
+impl RegexMatch {
+    pub fn new(pattern: &str) -> Result<RegexMatch, regex::Error> {
+        return Ok(RegexMatch {
+            pattern: String::from(pattern),
+            regex: Regex::new(&format!("^(?:{})$", pattern))?,
+        });
+    }
+}
+
+impl fmt::Display for RegexMatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{} {}", self.kind(), self.pattern);
+    }
+}
+
+impl PasswordPolicy for RegexMatch {
+    fn kind(&self) -> &'static str {
+        return "regex";
+    }
+
+    fn violation(&self, password: &str) -> Option<String> {
+        return if self.regex.is_match(password) { None } else { Some(String::from("doesn't match")) };
+    }
+}
+
+pub struct ForbiddenCharacters(pub Vec<char>);
+
+impl fmt::Display for ForbiddenCharacters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{} {}", self.kind(), self.0.iter().collect::<String>());
+    }
+}
+
+impl PasswordPolicy for ForbiddenCharacters {
+    fn kind(&self) -> &'static str {
+        return "forbid";
+    }
+
+    fn violation(&self, password: &str) -> Option<String> {
+        return password.chars().find(|character| self.0.contains(character)).map(|character| format!("contains '{}'", character));
+    }
+}
+
+pub struct MinimumDistinct(pub usize);
+
+impl fmt::Display for MinimumDistinct {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{} {}", self.kind(), self.0);
+    }
+}
+
+fn distinct_characters(password: &str) -> usize {
+    let mut characters: Vec<char> = password.chars().collect();
+    characters.sort();
+    characters.dedup();
+    return characters.len();
+}
+
+impl PasswordPolicy for MinimumDistinct {
+    fn kind(&self) -> &'static str {
+        return "distinct";
+    }
+
+    fn violation(&self, password: &str) -> Option<String> {
+        let distinct = distinct_characters(password);
+        if distinct >= self.0 {
+            return None;
+        }
+
+        return Some(format!("has {} different characters, expected at least {}", distinct, self.0));
+    }
+
+    fn out_of_range(&self, password: &str) -> Option<usize> {
+        return Some(self.0.saturating_sub(distinct_characters(password)));
+    }
+}
+
+// How to read a clause that is just "<a>-<b> <letter>", as the puzzle input's are.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interpretation {
+    Count,
+    Positions,
+}
+
+impl Interpretation {
+    pub fn from_name(name: &str) -> Option<Interpretation> {
+        return match name {
+            "count" => Some(Interpretation::Count),
+            "positions" => Some(Interpretation::Positions),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for Interpretation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Interpretation::Count => write!(f, "count"),
+            Interpretation::Positions => write!(f, "positions"),
+        };
+    }
+}
+
+fn parse_range(text: &str, interpretation: Interpretation) -> Result<Box<dyn PasswordPolicy>, &'static str> {
+    let expected = match interpretation {
+        Interpretation::Count => COUNT_FORM,
+        Interpretation::Positions => POSITIONS_FORM,
+    };
+
+    let captures = RANGE_REGEX.captures(text).ok_or(expected)?;
+    let first: usize = captures["first"].parse().map_err(|_| expected)?;
+    let second: usize = captures["second"].parse().map_err(|_| expected)?;
+    let letter = captures["letter"].chars().next().ok_or(expected)?;
+
+    return match interpretation {
+        Interpretation::Count => Ok(Box::new(CountRange { letter: letter, min: first, max: second })),
+        Interpretation::Positions if first > 0 && second > 0 => Ok(Box::new(PositionalXor { letter: letter, first: first, second: second })),
+        Interpretation::Positions => Err(expected),
+    };
+}
+
+// Reads one clause of a policy line. The error is what was expected instead.
+//
+//     1-3 a                 the puzzle's own clause, read as a count or as positions depending on `interpretation`
+//     count 1-3 a           'a' appears 1 to 3 times
+//     positions 1-3 a       'a' is at exactly one of positions 1 and 3
+//     regex [a-z]+          the whole password matches the regular expression
+//     forbid xyz            none of 'x', 'y' or 'z' appear
+//     distinct 4            at least 4 different characters
+//
+// The policy line is split into clauses at "; " and from the password at ": ", so a regular expression can't contain
+// either of them.
+pub fn parse_clause(clause: &str, interpretation: Interpretation) -> Result<Box<dyn PasswordPolicy>, &'static str> {
+    let (keyword, rest) = clause.split_once(' ').unwrap_or((clause, ""));
+    return match keyword {
+        "count" => parse_range(rest, Interpretation::Count),
+        "positions" => parse_range(rest, Interpretation::Positions),
+        "regex" if rest.contains("; ") || rest.contains(": ") => Err("a regular expression without \"; \" or \": \" in it after \"regex\""),
+        "regex" => match RegexMatch::new(rest) {
+            Ok(regex) if !rest.is_empty() => Ok(Box::new(regex)),
+            _ => Err("a valid regular expression after \"regex\""),
+        },
+        "forbid" if !rest.is_empty() => Ok(Box::new(ForbiddenCharacters(rest.chars().collect()))),
+        "forbid" => Err("the forbidden characters after \"forbid\""),
+        "distinct" => match rest.parse() {
+            Ok(count) => Ok(Box::new(MinimumDistinct(count))),
+            Err(_) => Err("a number after \"distinct\""),
+        },
+        _ => parse_range(clause, interpretation),
+    };
+}
@@ -0,0 +1,22 @@
+use aoc_common::{Input, Value};
+
+pub use day2a::policy::{self, Interpretation};
+pub use day2a::{audit, command};
+pub use day2a::{count_valid, rejections, Error, Password, Rejection};
+
+pub fn parse_file(input: &Input) -> Result<Vec<Password>, Error> {
+    return day2a::parse_file_with(input, Interpretation::Positions);
+}
+
+pub fn solve(passwords: &Vec<Password>) -> Result<usize, Error> {
+    return day2a::solve(passwords);
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    return count_valid(input, Interpretation::Positions);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let passwords = parse_file(input)?;
+    return day2a::solve_with_details(&passwords);
+}
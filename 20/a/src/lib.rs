@@ -0,0 +1,495 @@
+use std::error;
+use std::fmt;
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{json, Input, InputError, Record, Value};
+use log::{debug, info, trace};
+
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    id: i64,
+    orientation: String,
+    top: Vec<char>,
+    bottom: Vec<char>,
+    left: Vec<char>,
+    right: Vec<char>
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    EmptyTile(i64),
+    TooSmall { id: i64, size: usize },
+    DuplicateTile(i64),
+    MismatchedTileSize { id: i64, expected: usize, found: usize },
+    NotSquare(usize),
+    NoArrangement,
+    TooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::EmptyTile(id) => write!(f, "Tile {} has no image data", id),
+            Error::TooSmall { id, size } => write!(f, "Tile {} is {}x{}, but tiles have to be at least 2x2 to have edges", id, size, size),
+            Error::DuplicateTile(id) => write!(f, "Tile {} appears more than once", id),
+            Error::MismatchedTileSize { id, expected, found } => write!(f, "Tile {} is {} pixels wide, expected {} like the first tile", id, found, expected),
+            Error::NotSquare(count) => write!(f, "{} tiles can't be arranged into a square", count),
+            Error::NoArrangement => write!(f, "Couldn't arrange the tiles"),
+            Error::TooLarge => write!(f, "The product of the corner tile ids doesn't fit in a 64 bit number"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn format_tile(t: &Tile) -> String {
+    let mut text = format!("Tile {} ({}):\n\t", t.id, t.orientation);
+    for c in &t.top {
+        text.push(*c);
+    }
+    text.push('\n');
+    assert!(t.top[0] == t.left[0]);
+    assert!(t.top[t.top.len()-1] == t.right[0]);
+    assert!(t.bottom[0] == t.left[t.left.len()-1]);
+    assert!(t.bottom[t.bottom.len()-1] == t.right[t.right.len()-1]);
+
+    for i in 1..(t.top.len()-1) {
+        text.push('\t');
+        text.push(t.left[i]);
+        for _j in 1..(t.top.len()-1) {
+            text.push(' ');
+        }
+        text.push(t.right[i]);
+        text.push('\n');
+    }
+    text.push('\t');
+    for c in &t.bottom {
+        text.push(*c);
+    }
+    text.push('\n');
+
+    return text;
+}
+
+fn format_tiles(tiles: &Vec<Tile>) -> String {
+    return tiles.iter().map(format_tile).collect::<Vec<String>>().join("\n");
+}
+
+fn make_rotations(tile: &Tile, flip_type: &str) -> Vec<Tile> {
+    let mut tiles = Vec::new();
+
+    let mut reversed_top = tile.top.clone();
+    reversed_top.reverse();
+
+    let mut reversed_bottom = tile.bottom.clone();
+    reversed_bottom.reverse();
+
+    let mut reversed_left = tile.left.clone();
+    reversed_left.reverse();
+
+    let mut reversed_right = tile.right.clone();
+    reversed_right.reverse();
+
+    // regular
+    tiles.push(tile.clone()); 
+    
+    // 90 rotation
+    tiles.push(Tile {
+        id: tile.id,
+        orientation: format!("{}, {} rotation", flip_type, 90),
+        top: reversed_left.clone(),
+        bottom: reversed_right.clone(),
+        left: tile.bottom.clone(),
+        right: tile.top.clone(),
+    });
+
+    // 180 rotation
+    tiles.push(Tile {
+        id: tile.id,
+        orientation: format!("{}, {} rotation", flip_type, 180),
+        top: reversed_bottom.clone(),
+        bottom: reversed_top.clone(),
+        left: reversed_right.clone(),
+        right: reversed_left.clone(),
+    });
+    
+
+    // 270 rotation
+    tiles.push(Tile {
+        id: tile.id,
+        orientation: format!("{}, {} rotation", flip_type, 270),
+        top: tile.right.clone(),
+        bottom: tile.left.clone(),
+        left: reversed_top.clone(),
+        right: reversed_bottom.clone(),
+    });
+
+    return tiles;
+}
+
+fn make_rotations_and_flips(tile: &Tile) -> Vec<Tile> {
+    let mut tiles = Vec::new();
+
+    let mut reversed_top = tile.top.clone();
+    reversed_top.reverse();
+
+    let mut reversed_bottom = tile.bottom.clone();
+    reversed_bottom.reverse();
+
+    let mut reversed_left = tile.left.clone();
+    reversed_left.reverse();
+
+    let mut reversed_right = tile.right.clone();
+    reversed_right.reverse();
+    
+    // regular
+    tiles.append(&mut make_rotations(tile, "regular")); 
+
+    // Vertical flip
+    let vflip = Tile {
+        id: tile.id,
+        orientation: String::from("vertical flip"),
+        top: tile.bottom.clone(),
+        bottom: tile.top.clone(),
+        left: reversed_left.clone(),
+        right: reversed_right.clone(),
+    };
+    tiles.append(&mut make_rotations(&vflip, "vertical flip"));
+
+    // Horizontal flip
+    let hflip = Tile {
+        id: tile.id,
+        orientation: String::from("horizonal flip"),
+        top: reversed_top.clone(),
+        bottom: reversed_bottom.clone(),
+        left: tile.right.clone(),
+        right: tile.left.clone(),
+    };
+    tiles.append(&mut make_rotations(&hflip, "horizonal flip"));
+
+    trace!("Orientations of tile {}:\n{}", tile.id, format_tiles(&tiles));
+
+    return tiles;
+}
+
+// The data has to be square and at least 2x2, which `parse_tile` checks.
+fn make_tiles(id: i64, data: &Vec<String>) -> Vec<Tile> {
+
+    let mut tile = Tile {
+        id: id,
+        orientation: String::from("regular"),
+        top: data[0].chars().collect(),
+        bottom: data[data.len() - 1].chars().collect(),
+        left: Vec::new(),
+        right: Vec::new(),
+    };
+
+    for i in 0..data.len() {
+        let row: Vec<char> = data[i].chars().collect();
+        tile.left.push(row[0]);
+        tile.right.push(row[row.len() - 1]);
+    }
+
+    return make_rotations_and_flips(&tile);
+}
+
+fn parse_tile(record: &Record) -> Result<Vec<Tile>, Error> {
+    let lines: Vec<String> = record.lines.iter().map(|l| String::from(l.trim())).collect();
+
+    // "Tile 1234:" followed by the image data
+    let header = &lines[0];
+    let id = match header.strip_prefix("Tile ").and_then(|id| id.strip_suffix(':')).map(|id| id.parse::<i64>()) {
+        Some(Ok(id)) if id >= 0 => id,
+        _ => return Err(Error::Malformed { line: record.line, text: String::from(header), expected: "\"Tile <id>:\"" })
+    };
+
+    let data = lines[1..].to_vec();
+    if data.is_empty() {
+        return Err(Error::EmptyTile(id));
+    }
+
+    for (offset, row) in data.iter().enumerate() {
+        let is_valid = row.len() == data.len() && row.chars().all(|pixel| pixel == '.' || pixel == '#');
+        if !is_valid {
+            return Err(Error::Malformed { line: record.line + 1 + offset, text: String::from(row), expected: "a square image of '.' and '#'" });
+        }
+    }
+    if data.len() < 2 {
+        return Err(Error::TooSmall { id: id, size: data.len() });
+    }
+
+    return Ok(make_tiles(id, &data));
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Vec<Tile>>, Error> {
+    let records = aoc_common::read_records(input)?;
+
+    let mut tiles: Vec<Vec<Tile>> = Vec::new();
+    for record in &records {
+        let tile = parse_tile(record)?;
+        let id = tile[0].id;
+        if tiles.iter().any(|other| other[0].id == id) {
+            return Err(Error::DuplicateTile(id));
+        }
+
+        if let Some(first) = tiles.first() {
+            if tile[0].top.len() != first[0].top.len() {
+                return Err(Error::MismatchedTileSize { id: id, expected: first[0].top.len(), found: tile[0].top.len() });
+            }
+        }
+
+        tiles.push(tile);
+    }
+
+    let dimensions = (tiles.len() as f32).sqrt() as usize;
+    if tiles.is_empty() || dimensions * dimensions != tiles.len() {
+        return Err(Error::NotSquare(tiles.len()));
+    }
+
+    return Ok(tiles);
+}
+
+type TileOrientation = (i64, usize);
+
+const NO_TILE: TileOrientation = (-1, 1000);
+
+type Cache = HashMap<TileOrientation, Vec<TileOrientation>>;
+
+fn make_bottom_cache(tiles: &Vec<Vec<Tile>>) -> (Cache, HashMap<usize, String>) {
+    let mut cache = HashMap::new();
+    let mut orientations = HashMap::new();
+
+    for i in 0..tiles[0].len() {
+        orientations.insert(i, tiles[0][i].orientation.clone());
+    }
+
+    for i in 0..tiles.len() {
+        let i_orientations = &tiles[i];
+
+        for i_o in 0..(i_orientations.len()) {
+            let i_t = &i_orientations[i_o];
+            let t = cache.entry((i_t.id, i_o)).or_insert_with(|| Vec::new());
+            for j in 0..tiles.len() {
+                if i == j {
+                    continue;
+                }
+
+                let j_orientations = &tiles[j];
+
+                for j_o in 0..j_orientations.len() {
+                    let j_t = &j_orientations[j_o];
+                    if i_t.bottom == j_t.top {
+                        t.push((j_t.id, j_o));
+                    }
+                }
+            }
+        }
+    }
+
+    return (cache, orientations);
+}
+
+fn make_right_cache(tiles: &Vec<Vec<Tile>>) -> Cache {
+    let mut cache = HashMap::new();
+
+    for i in 0..tiles.len() {
+        let i_orientations = &tiles[i];
+
+        for i_o in 0..(i_orientations.len()) {
+            let i_t = &i_orientations[i_o];
+            let t = cache.entry((i_t.id, i_o)).or_insert_with(|| Vec::new());
+            for j in 0..tiles.len() {
+                if i == j {
+                    continue;
+                }
+
+                let j_orientations = &tiles[j];
+
+                for j_o in 0..j_orientations.len() {
+                    let j_t = &j_orientations[j_o];
+                    if i_t.right == j_t.left {
+                        t.push((j_t.id, j_o));
+                    }
+                }
+            }
+        }
+    }
+
+    return cache;
+}
+
+struct TileData {
+    tiles: HashMap<i64, Vec<TileOrientation>>,
+    right_cache: Cache,
+    bottom_cache: Cache,
+    orientations: HashMap<usize, String>
+}
+
+fn path_to_string(path: &Vec<i64>) -> String {
+    return path.iter().map(|i| i.to_string()).collect();
+}
+
+fn orientation_name<'a>(tile_data: &'a TileData, tile: &TileOrientation) -> &'a str {
+    return tile_data.orientations.get(&tile.1).map_or("unknown orientation", |name| name.as_str());
+}
+
+fn find_tile(x: usize, y: usize, tile_data: &TileData, used_tiles: &mut Vec<i64>, board: &mut Vec<Vec<TileOrientation>>, memo: &mut HashSet<String>) -> bool {
+    let current_path = path_to_string(used_tiles);
+    if memo.contains(&current_path) {
+        return false;
+    }
+    
+    if x == 0 && y == 0 {
+        let available_tiles: Vec<(&i64, &Vec<TileOrientation>)> = tile_data.tiles.iter().filter(|(k,_v)| !used_tiles.contains(k)).collect();
+        for (_id, tile_set) in available_tiles {
+            debug!("Searching from tile {} in the top left corner", tile_set[0].0);
+            for tile in tile_set {
+                trace!("{} ({}) at ({},{})", tile.0, orientation_name(tile_data, tile), x, y);
+                board[x][y] = *tile;
+                used_tiles.push(tile.0);
+                // A single tile is the whole board already.
+                let result = used_tiles.len() == tile_data.tiles.len() || find_tile(1, 0, tile_data, used_tiles, board, memo);
+                if result {
+                    return true;
+                } else {
+                    used_tiles.pop();
+                }
+            }
+        }
+
+        return false;
+    }
+
+    let up_candidates: HashSet<&TileOrientation> = if y > 0 {
+        let up = board[x][y - 1];
+        match tile_data.bottom_cache.get(&up) {
+            None => return false,
+            Some(below) => below
+        }.iter().filter(|t| !used_tiles.contains(&t.0)).collect()
+    } else {
+        tile_data.bottom_cache.keys().filter(|t| !used_tiles.contains(&t.0)).collect()
+    };
+
+    let left_candidates: HashSet<&TileOrientation> = if x > 0 {
+        let left = board[x - 1][y];
+        match tile_data.right_cache.get(&left) {
+            None => return false,
+            Some(right) => right
+        }.iter().filter(|t| !used_tiles.contains(&t.0)).collect()
+    } else {
+        tile_data.right_cache.keys().filter(|t| !used_tiles.contains(&t.0)).collect()
+    };
+
+
+    for candidate in up_candidates.intersection(&left_candidates) {
+        trace!("{} ({}) at ({},{})", candidate.0, orientation_name(tile_data, candidate), x, y);
+        board[x][y] = **candidate;
+        used_tiles.push(candidate.0);
+
+        if used_tiles.len() == tile_data.tiles.len() {
+            debug!("Final board: {:?}", board);
+            return true;
+        }
+
+        let next_x = x + 1;
+        let result = if next_x == board.len() {
+            find_tile(0, y + 1, tile_data, used_tiles, board, memo) 
+        } else {
+            find_tile(next_x, y, tile_data, used_tiles, board, memo)
+        };
+        
+        if result {
+            return true;
+        } else {
+            memo.insert(path_to_string(used_tiles));
+            used_tiles.pop();
+        }
+    }
+
+    board[x][y] = NO_TILE;
+
+    return false;
+}
+
+// The product of the corner IDs along with the corners themselves.
+fn arrange_tiles(tiles: &Vec<Vec<Tile>>) -> Result<(i64, Value), Error> {
+    let dimensions = (tiles.len() as f32).sqrt() as usize;
+    info!("Dimensions: {}x{} ({} total tiles)", dimensions, dimensions, tiles.len());
+
+    let mut board = Vec::new();
+    for x in 0..dimensions {
+        board.push(Vec::new());
+        for _y in 0..dimensions {
+            board[x].push(NO_TILE);
+        }
+    }
+
+    let mut available_tiles = HashMap::new();
+    for tile in tiles {
+        let mut ts = Vec::new();
+        for t in 0..(tile.len() - 1) {
+            ts.push((tile[0].id, t));
+        }
+        available_tiles.insert(tile[0].id, ts);
+    }
+    let mut used_tiles = Vec::new();
+
+    debug!("Available tiles: {:?}", available_tiles.keys());
+
+    let (bottom_cache, orientations) = make_bottom_cache(&tiles);
+    let right_cache = make_right_cache(&tiles);
+
+    let tile_data = TileData {
+        tiles: available_tiles,
+        right_cache: right_cache,
+        bottom_cache: bottom_cache,
+        orientations: orientations
+    };
+
+    let mut memo = HashSet::new();
+
+    if !find_tile(0,0, &tile_data, &mut used_tiles, &mut board, &mut memo) {
+        return Err(Error::NoArrangement);
+    }
+
+    let end = board.len() - 1;
+    let corners = [board[0][0].0, board[end][0].0, board[0][end].0, board[end][end].0];
+    let product = corners.iter().try_fold(1i64, |product, id| product.checked_mul(*id)).ok_or(Error::TooLarge)?;
+    return Ok((product, json!({ "dimensions": dimensions, "corners": corners })));
+}
+
+pub fn solve(tiles: &Vec<Vec<Tile>>) -> Result<i64, Error> {
+    return Ok(arrange_tiles(tiles)?.0);
+}
+
+pub fn solve_with_details(tiles: &Vec<Vec<Tile>>) -> Result<(i64, Value), Error> {
+    return arrange_tiles(tiles);
+}
+
+pub fn part1(input: &Input) -> Result<i64, Error> {
+    let tiles = parse_file(input)?;
+    return solve(&tiles);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let tiles = parse_file(input)?;
+    return solve_with_details(&tiles);
+}
@@ -0,0 +1,716 @@
+use std::error;
+use std::fmt;
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{json, Input, InputError, Record, Value};
+use log::{debug, info, trace};
+
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    id: i64,
+    orientation: String,
+    top: Vec<char>,
+    bottom: Vec<char>,
+    left: Vec<char>,
+    right: Vec<char>,
+    data: Vec<Vec<char>>
+}
+
+fn format_tile(t: &Tile) -> String {
+    let mut text = format!("Tile {} ({}):\n", t.id, t.orientation);
+    for y in 0..t.data.len() {
+        text.push('\t');
+        for x in 0..t.data.len() {
+            text.push(t.data[x][y]);
+        }
+        text.push('\n');
+    }
+
+    return text;
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    EmptyTile(i64),
+    TooSmall { id: i64, size: usize },
+    DuplicateTile(i64),
+    MismatchedTileSize { id: i64, expected: usize, found: usize },
+    NotSquare(usize),
+    NoArrangement,
+    NoSeaMonsters,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::EmptyTile(id) => write!(f, "Tile {} has no image data", id),
+            Error::TooSmall { id, size } => write!(f, "Tile {} is {}x{}, but tiles have to be at least 2x2 to have edges", id, size, size),
+            Error::DuplicateTile(id) => write!(f, "Tile {} appears more than once", id),
+            Error::MismatchedTileSize { id, expected, found } => write!(f, "Tile {} is {} pixels wide, expected {} like the first tile", id, found, expected),
+            Error::NotSquare(count) => write!(f, "{} tiles can't be arranged into a square", count),
+            Error::NoArrangement => write!(f, "Couldn't arrange the tiles"),
+            Error::NoSeaMonsters => write!(f, "Couldn't find any sea monsters"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn format_tiles(tiles: &Vec<Tile>) -> String {
+    return tiles.iter().map(format_tile).collect::<Vec<String>>().join("\n");
+}
+
+fn horizontal_flip(x: usize, y: usize, max: usize) -> (usize, usize) {
+    return (max - x, y);
+}
+
+
+fn vertical_flip(x: usize, y: usize, max: usize) -> (usize, usize) {
+    return (x, max - y);
+}
+
+fn rotate(x: usize, y: usize, max: usize, count: usize) -> (usize, usize) {
+    let mut new_x = x;
+    let mut new_y = y;
+    for _ in 0..count {
+        let old_x = new_x;
+        new_x = max - new_y;
+        new_y = old_x;
+    }
+    return (new_x, new_y);
+}
+
+fn horizontal_flip_tile(tile: &Vec<Vec<char>>) -> Vec<Vec<char>> {
+    let mut new_tile = Vec::new();
+    for _ in 0..tile.len() {
+        new_tile.push(vec!['0'; tile.len()]);
+    }
+
+    for x in 0..tile.len() {
+        for y in 0..tile.len() {
+            let (ox, oy) = horizontal_flip(x, y, tile.len() - 1);
+            new_tile[x][y] = tile[ox][oy];
+        }
+    }
+
+    return new_tile;
+}
+
+fn vertical_flip_tile(tile: &Vec<Vec<char>>) -> Vec<Vec<char>> {
+    let mut new_tile = Vec::new();
+    for _ in 0..tile.len() {
+        new_tile.push(vec!['0'; tile.len()]);
+    }
+
+    for x in 0..tile.len() {
+        for y in 0..tile.len() {
+            let (ox, oy) = vertical_flip(x, y, tile.len() - 1);
+            new_tile[x][y] = tile[ox][oy];
+        }
+    }
+
+    return new_tile;
+}
+
+fn rotate_tile(tile: &Vec<Vec<char>>, count: usize) -> Vec<Vec<char>> {
+    let mut new_tile = Vec::new();
+    for _ in 0..tile.len() {
+        new_tile.push(vec!['0'; tile.len()]);
+    }
+    for x in 0..tile.len() {
+        for y in 0..tile.len() {
+            let (ox, oy) = rotate(x, y, tile.len() - 1, count);
+            new_tile[ox][oy] = tile[x][y];
+        }
+    }
+
+    return new_tile;
+}
+
+fn make_rotations(tile: &Tile, flip_type: &str) -> Vec<Tile> {
+    let mut tiles = Vec::new();
+
+    let mut reversed_top = tile.top.clone();
+    reversed_top.reverse();
+
+    let mut reversed_bottom = tile.bottom.clone();
+    reversed_bottom.reverse();
+
+    let mut reversed_left = tile.left.clone();
+    reversed_left.reverse();
+
+    let mut reversed_right = tile.right.clone();
+    reversed_right.reverse();
+
+    // regular
+    tiles.push(tile.clone()); 
+    
+    // 90 rotation
+    tiles.push(Tile {
+        id: tile.id,
+        orientation: format!("{}, {} rotation", flip_type, 90),
+        top: reversed_left.clone(),
+        bottom: reversed_right.clone(),
+        left: tile.bottom.clone(),
+        right: tile.top.clone(),
+        data: rotate_tile(&tile.data, 1),
+    });
+
+    // 180 rotation
+    tiles.push(Tile {
+        id: tile.id,
+        orientation: format!("{}, {} rotation", flip_type, 180),
+        top: reversed_bottom.clone(),
+        bottom: reversed_top.clone(),
+        left: reversed_right.clone(),
+        right: reversed_left.clone(),
+        data: rotate_tile(&tile.data, 2),
+    });
+    
+
+    // 270 rotation
+    tiles.push(Tile {
+        id: tile.id,
+        orientation: format!("{}, {} rotation", flip_type, 270),
+        top: tile.right.clone(),
+        bottom: tile.left.clone(),
+        left: reversed_top.clone(),
+        right: reversed_bottom.clone(),
+        data: rotate_tile(&tile.data, 3),
+    });
+
+    return tiles;
+}
+
+fn make_rotations_and_flips(tile: &Tile) -> Vec<Tile> {
+    let mut tiles = Vec::new();
+
+    let mut reversed_top = tile.top.clone();
+    reversed_top.reverse();
+
+    let mut reversed_bottom = tile.bottom.clone();
+    reversed_bottom.reverse();
+
+    let mut reversed_left = tile.left.clone();
+    reversed_left.reverse();
+
+    let mut reversed_right = tile.right.clone();
+    reversed_right.reverse();
+    
+    // regular
+    tiles.append(&mut make_rotations(tile, "regular")); 
+
+    // Vertical flip
+    let vflip = Tile {
+        id: tile.id,
+        orientation: String::from("vertical flip"),
+        top: tile.bottom.clone(),
+        bottom: tile.top.clone(),
+        left: reversed_left.clone(),
+        right: reversed_right.clone(),
+        data: vertical_flip_tile(&tile.data),
+    };
+
+    tiles.append(&mut make_rotations(&vflip, "vertical flip"));
+
+    // Horizontal flip
+    let hflip = Tile {
+        id: tile.id,
+        orientation: String::from("horizonal flip"),
+        top: reversed_top.clone(),
+        bottom: reversed_bottom.clone(),
+        left: tile.right.clone(),
+        right: tile.left.clone(),
+        data: horizontal_flip_tile(&tile.data),
+    };
+    tiles.append(&mut make_rotations(&hflip, "horizonal flip"));
+
+    trace!("Orientations of tile {}:\n{}", tile.id, format_tiles(&tiles));
+
+    return tiles;
+}
+
+// The data has to be square and at least 2x2, which `parse_tile` checks.
+fn make_tiles(id: i64, data: &Vec<String>) -> Vec<Tile> {
+
+    let mut tile = Tile {
+        id: id,
+        orientation: String::from("regular"),
+        top: data[0].chars().collect(),
+        bottom: data[data.len() - 1].chars().collect(),
+        left: Vec::new(),
+        right: Vec::new(),
+        data: Vec::new(),
+    };
+
+    for i in 0..data.len() {
+        let row: Vec<char> = data[i].chars().collect();
+        tile.left.push(row[0]);
+        tile.right.push(row[row.len() - 1]);
+        tile.data.push(data[i].chars().collect());
+    }
+
+    tile.data = rotate_tile(&vertical_flip_tile(&tile.data), 1);
+
+    return make_rotations_and_flips(&tile);
+}
+
+fn parse_tile(record: &Record) -> Result<Vec<Tile>, Error> {
+    let lines: Vec<String> = record.lines.iter().map(|l| String::from(l.trim())).collect();
+
+    // "Tile 1234:" followed by the image data
+    let header = &lines[0];
+    let id = match header.strip_prefix("Tile ").and_then(|id| id.strip_suffix(':')).map(|id| id.parse::<i64>()) {
+        Some(Ok(id)) if id >= 0 => id,
+        _ => return Err(Error::Malformed { line: record.line, text: String::from(header), expected: "\"Tile <id>:\"" })
+    };
+
+    let data = lines[1..].to_vec();
+    if data.is_empty() {
+        return Err(Error::EmptyTile(id));
+    }
+
+    for (offset, row) in data.iter().enumerate() {
+        let is_valid = row.len() == data.len() && row.chars().all(|pixel| pixel == '.' || pixel == '#');
+        if !is_valid {
+            return Err(Error::Malformed { line: record.line + 1 + offset, text: String::from(row), expected: "a square image of '.' and '#'" });
+        }
+    }
+    if data.len() < 2 {
+        return Err(Error::TooSmall { id: id, size: data.len() });
+    }
+
+    return Ok(make_tiles(id, &data));
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Vec<Tile>>, Error> {
+    let records = aoc_common::read_records(input)?;
+
+    let mut tiles: Vec<Vec<Tile>> = Vec::new();
+    for record in &records {
+        let tile = parse_tile(record)?;
+        let id = tile[0].id;
+        if tiles.iter().any(|other| other[0].id == id) {
+            return Err(Error::DuplicateTile(id));
+        }
+
+        if let Some(first) = tiles.first() {
+            if tile[0].top.len() != first[0].top.len() {
+                return Err(Error::MismatchedTileSize { id: id, expected: first[0].top.len(), found: tile[0].top.len() });
+            }
+        }
+
+        tiles.push(tile);
+    }
+
+    let dimensions = (tiles.len() as f32).sqrt() as usize;
+    if tiles.is_empty() || dimensions * dimensions != tiles.len() {
+        return Err(Error::NotSquare(tiles.len()));
+    }
+
+    return Ok(tiles);
+}
+
+type TileOrientation = (i64, usize);
+
+const NO_TILE: TileOrientation = (-1, 1000);
+
+type Cache = HashMap<TileOrientation, Vec<TileOrientation>>;
+
+fn make_bottom_cache(tiles: &Vec<Vec<Tile>>) -> (Cache, HashMap<usize, String>) {
+    let mut cache = HashMap::new();
+    let mut orientations = HashMap::new();
+
+    for i in 0..tiles[0].len() {
+        orientations.insert(i, tiles[0][i].orientation.clone());
+    }
+
+    for i in 0..tiles.len() {
+        let i_orientations = &tiles[i];
+
+        for i_o in 0..(i_orientations.len()) {
+            let i_t = &i_orientations[i_o];
+            let t = cache.entry((i_t.id, i_o)).or_insert_with(|| Vec::new());
+            for j in 0..tiles.len() {
+                if i == j {
+                    continue;
+                }
+
+                let j_orientations = &tiles[j];
+
+                for j_o in 0..j_orientations.len() {
+                    let j_t = &j_orientations[j_o];
+                    if i_t.bottom == j_t.top {
+                        t.push((j_t.id, j_o));
+                    }
+                }
+            }
+        }
+    }
+
+    return (cache, orientations);
+}
+
+fn make_right_cache(tiles: &Vec<Vec<Tile>>) -> Cache {
+    let mut cache = HashMap::new();
+
+    for i in 0..tiles.len() {
+        let i_orientations = &tiles[i];
+
+        for i_o in 0..(i_orientations.len()) {
+            let i_t = &i_orientations[i_o];
+            let t = cache.entry((i_t.id, i_o)).or_insert_with(|| Vec::new());
+            for j in 0..tiles.len() {
+                if i == j {
+                    continue;
+                }
+
+                let j_orientations = &tiles[j];
+
+                for j_o in 0..j_orientations.len() {
+                    let j_t = &j_orientations[j_o];
+                    if i_t.right == j_t.left {
+                        t.push((j_t.id, j_o));
+                    }
+                }
+            }
+        }
+    }
+
+    return cache;
+}
+
+struct TileData {
+    tiles: HashMap<i64, Vec<TileOrientation>>,
+    right_cache: Cache,
+    bottom_cache: Cache,
+    orientations: HashMap<usize, String>
+}
+
+fn path_to_string(path: &Vec<i64>) -> String {
+    return path.iter().map(|i| i.to_string()).collect();
+}
+
+fn orientation_name<'a>(tile_data: &'a TileData, tile: &TileOrientation) -> &'a str {
+    return tile_data.orientations.get(&tile.1).map_or("unknown orientation", |name| name.as_str());
+}
+
+fn find_tile(x: usize, y: usize, tile_data: &TileData, used_tiles: &mut Vec<i64>, board: &mut Vec<Vec<TileOrientation>>, memo: &mut HashSet<String>) -> bool {
+    let current_path = path_to_string(used_tiles);
+    if memo.contains(&current_path) {
+        return false;
+    }
+    
+    if x == 0 && y == 0 {
+        let available_tiles: Vec<(&i64, &Vec<TileOrientation>)> = tile_data.tiles.iter().filter(|(k,_v)| !used_tiles.contains(k)).collect();
+        for (_id, tile_set) in available_tiles {
+                debug!("Searching from tile {} in the top left corner", tile_set[0].0);
+                for tile in tile_set {
+                    trace!("{} ({}) at ({},{})", tile.0, orientation_name(tile_data, tile), x, y);
+                    board[x][y] = *tile;
+                    used_tiles.push(tile.0);
+                    // A single tile is the whole board already.
+                    let result = used_tiles.len() == tile_data.tiles.len() || find_tile(1, 0, tile_data, used_tiles, board, memo);
+                    if result {
+                        return true;
+                    } else {
+                        used_tiles.pop();
+                    }
+                }
+        }
+
+        return false;
+    }
+
+    let up_candidates: HashSet<&TileOrientation> = if y > 0 {
+        let up = board[x][y - 1];
+        match tile_data.bottom_cache.get(&up) {
+            None => return false,
+            Some(below) => below
+        }.iter().filter(|t| !used_tiles.contains(&t.0)).collect()
+    } else {
+        tile_data.bottom_cache.keys().filter(|t| !used_tiles.contains(&t.0)).collect()
+    };
+
+    let left_candidates: HashSet<&TileOrientation> = if x > 0 {
+        let left = board[x - 1][y];
+        match tile_data.right_cache.get(&left) {
+            None => return false,
+            Some(right) => right
+        }.iter().filter(|t| !used_tiles.contains(&t.0)).collect()
+    } else {
+        tile_data.right_cache.keys().filter(|t| !used_tiles.contains(&t.0)).collect()
+    };
+
+
+    for candidate in up_candidates.intersection(&left_candidates) {
+        trace!("{} ({}) at ({},{})", candidate.0, orientation_name(tile_data, candidate), x, y);
+        board[x][y] = **candidate;
+        used_tiles.push(candidate.0);
+
+        if used_tiles.len() == tile_data.tiles.len() {
+            debug!("Final board: {:?}", board);
+            return true;
+        }
+
+        let next_x = x + 1;
+        let result = if next_x == board.len() {
+            find_tile(0, y + 1, tile_data, used_tiles, board, memo) 
+        } else {
+            find_tile(next_x, y, tile_data, used_tiles, board, memo)
+        };
+        
+        if result {
+            return true;
+        } else {
+            memo.insert(path_to_string(used_tiles));
+            used_tiles.pop();
+        }
+    }
+
+    board[x][y] = NO_TILE;
+
+    return false;
+}
+
+fn combine_board(board: &Vec<Vec<TileOrientation>>, tiles: &HashMap<TileOrientation, &Tile>) -> Vec<Vec<char>> {
+    let mut realized_board = Vec::new();
+    for x in 0..board.len() {
+        realized_board.push(Vec::new());
+        for y in 0..board.len() {
+            realized_board[x].push(tiles.get(&board[x][y]).unwrap());
+        }
+    }
+
+    let mut orientations = String::new();
+    let mut names = String::new();
+    for y in 0..board[0].len() {
+        for x in 0..board.len() {
+            orientations += &format!("{:?} ", board[x][y]);
+            names += &format!("Tile {} ({}) ", realized_board[x][y].id, realized_board[x][y].orientation);
+        }
+        orientations.push('\n');
+        names.push('\n');
+    }
+    debug!("Tile orientations:\n{}", orientations);
+    debug!("Tiles:\n{}", names);
+
+    let tile_height = realized_board[0][0].data.len() - 2;
+
+    let mut combined = Vec::new();
+    for _ in 0..(tile_height * realized_board.len()) {
+        combined.push(vec!['0'; tile_height * realized_board.len()]);
+    }
+
+    let mut arranged = String::new();
+    for y in 0..board.len() {
+        for h in 0..(tile_height + 2) {
+            for x in 0..board.len() {
+                for l in 0..(tile_height + 2) {
+                    arranged.push(realized_board[x][y].data[l][h]);
+                }
+                arranged.push(' ');
+            }
+            arranged.push('\n');
+        }
+        arranged.push('\n');
+    }
+    trace!("Board:\n{}", arranged);
+
+    for x in 0..realized_board.len() {
+        for y in 0..realized_board.len() {
+            for w in 1..(realized_board[x][y].data.len() - 1) {
+                for l in 1..(realized_board[x][y].data.len() - 1) {
+                    combined[(x * tile_height) + (w - 1)][(y * tile_height) + (l - 1)] = realized_board[x][y].data[w][l];
+                }
+            }
+        }
+    }
+
+    debug!("Rows: {}, Cols: {}", combined.len(), combined.first().map_or(0, |row| row.len()));
+    trace!("Image:\n{}", format_image(&combined));
+
+    return combined;
+}
+
+fn arrange_tiles(tiles: &Vec<Vec<Tile>>) -> Result<Vec<Vec<char>>, Error> {
+    let dimensions = (tiles.len() as f32).sqrt() as usize;
+    info!("Dimensions: {}x{} ({} total tiles)", dimensions, dimensions, tiles.len());
+
+    let mut board = Vec::new();
+    for x in 0..dimensions {
+        board.push(Vec::new());
+        for _y in 0..dimensions {
+            board[x].push(NO_TILE);
+        }
+    }
+
+    let mut available_tiles = HashMap::new();
+    let mut tile_mapping = HashMap::new();
+    for i in 0..tiles.len() {
+        let tile = &tiles[i];
+        let mut ts = Vec::new();
+        for t in 0..tile.len() {
+            ts.push((tile[0].id, t));
+            tile_mapping.insert((tile[0].id, t), &tile[t]);
+            trace!("Tile {} ({}) -> {}:\n{}", tile[t].id, tile[t].orientation, t, format_image(&tile[t].data));
+        }
+        available_tiles.insert(tile[0].id, ts);
+    }
+
+    let mut used_tiles = Vec::new();
+
+    debug!("Available tiles: {:?}", available_tiles.keys());
+
+    let (bottom_cache, orientations) = make_bottom_cache(&tiles);
+    let right_cache = make_right_cache(&tiles);
+
+    let tile_data = TileData {
+        tiles: available_tiles,
+        right_cache: right_cache,
+        bottom_cache: bottom_cache,
+        orientations: orientations
+    };
+
+    let mut memo = HashSet::new();
+
+    if !find_tile(0,0, &tile_data, &mut used_tiles, &mut board, &mut memo) {
+        return Err(Error::NoArrangement);
+    }
+
+    return Ok(combine_board(&board, &tile_mapping));
+}
+
+static SEA_MONSTER: [(usize, usize); 15] = [(18, 0), (0, 1), (5, 1), (6, 1), (11, 1), (12, 1), (17, 1), (18, 1), (19, 1), (1, 2), (4, 2), (7, 2), (10, 2), (13, 2), (16, 2)];
+const SEA_MONSTER_HEIGHT: usize = 3;
+const SEA_MONSTER_WIDTH: usize = 20;
+
+
+fn is_sea_monster(board: &Vec<Vec<char>>, x: usize, y: usize) -> bool {
+    if x + SEA_MONSTER_WIDTH <= board.len() && y + SEA_MONSTER_HEIGHT <= board[x].len() {
+        return SEA_MONSTER.iter().all(|(dx, dy)| board[x + dx][y + dy] == '#');
+    } else {
+        return false;
+    }
+}
+
+fn find_sea_monsters(board: &Vec<Vec<char>>) -> (usize, Vec<Vec<char>>) {
+    let mut b = board.clone();
+    let mut sea_monsters = Vec::new();
+
+    for x in 0..board.len() {
+        for y in 0..board[x].len() {
+            if is_sea_monster(board, x, y) {
+                sea_monsters.push((x, y));
+            }
+        }
+    }
+
+    if sea_monsters.is_empty() {
+        trace!("No sea monsters");
+    }
+
+    for (x, y) in &sea_monsters {
+        info!("Sea monster at: {:?}", (x, y));
+        for (dx, dy) in &SEA_MONSTER {
+            b[x + dx][y + dy] = 'O';
+        }
+    }
+
+    return (sea_monsters.len(), b);
+}
+
+fn format_image(board: &Vec<Vec<char>>) -> String {
+    let mut text = String::new();
+    for y in 0..board.first().map_or(0, |column| column.len()) {
+        for x in 0..board.len() {
+            text.push(board[x][y]);
+        }
+        text.push('\n');
+    }
+
+    return text;
+}
+
+fn count_rough_waters(board: &Vec<Vec<char>>) -> usize {
+    let mut count = 0;
+    for x in 0..board.len() {
+        for y in 0..board.len() {
+            if board[x][y] == '#' {
+                count += 1;
+            }
+        }
+    }
+
+    return count;
+}
+
+fn get_boards(board: &Vec<Vec<char>>) -> Vec<Vec<Vec<char>>> {
+    let mut boards = Vec::new();
+
+    boards.push(board.clone());
+
+    let hflip = horizontal_flip_tile(&board);
+    let vflip = vertical_flip_tile(&board);
+
+    for i in 1..=3 {
+        boards.push(rotate_tile(&board, i));
+        boards.push(rotate_tile(&hflip, i));
+        boards.push(rotate_tile(&vflip, i));
+    }
+
+    boards.push(hflip);
+    boards.push(vflip);
+
+    trace!("Boards count: {}", boards.len());
+    return boards;
+}
+
+pub fn solve(tiles: &Vec<Vec<Tile>>) -> Result<usize, Error> {
+    return Ok(solve_with_details(tiles)?.0);
+}
+
+// The roughness along with how many sea monsters there are and how big the image is.
+pub fn solve_with_details(tiles: &Vec<Vec<Tile>>) -> Result<(usize, Value), Error> {
+    let result = arrange_tiles(tiles)?;
+
+    let all = get_boards(&result);
+
+    for b in &all {
+        let (sea_monster_count, filled_sea_monsters) = find_sea_monsters(b);
+        if sea_monster_count != 0 {
+            debug!("Sea monsters:\n{}", format_image(&filled_sea_monsters));
+            return Ok((count_rough_waters(&filled_sea_monsters), json!({ "sea_monsters": sea_monster_count, "image_size": b.len() })));
+        }
+    }
+
+    return Err(Error::NoSeaMonsters);
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let tiles = parse_file(input)?;
+    return solve(&tiles);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let tiles = parse_file(input)?;
+    return solve_with_details(&tiles);
+}
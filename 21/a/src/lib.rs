@@ -0,0 +1,180 @@
+use std::error;
+use std::fmt;
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::{debug, trace};
+
+#[derive(Debug)]
+pub struct IngredientList {
+    ingredients: Vec<String>,
+    allergens: Vec<String>
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    NoIngredientFor(String),
+    Ambiguous,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::NoIngredientFor(allergen) => write!(f, "No ingredient appears in every food containing {}", allergen),
+            Error::Ambiguous => write!(f, "The foods don't narrow every allergen down to a single ingredient"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_line(line_number: usize, s: &str) -> Result<IngredientList, Error> {
+    let contains_location = match s.find("(contains") {
+        Some(location) if s.ends_with(')') => location,
+        _ => return Err(Error::Malformed { line: line_number, text: String::from(s), expected: "\"<ingredients> (contains <allergen>, <allergen>, ...)\"" })
+    };
+
+    let (ingredients, allergens) = s.split_at(contains_location);
+
+    let i = ingredients.trim().split(" ");
+    let a = allergens[9..(allergens.len() - 1)].trim().split(", ");
+
+    return Ok(IngredientList {
+        ingredients: i.map(|s| String::from(s)).collect(),
+        allergens: a.map(|s| String::from(s)).collect(),
+    });
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<IngredientList>, Error> {
+    return aoc_common::parse_lines_with(input, parse_line);
+}
+
+fn make_allergen_sets(lists: &Vec<IngredientList>) -> HashMap<String, Vec<HashSet<String>>> {
+    let mut map = HashMap::new();
+
+    for list in lists {
+        for allergen in &list.allergens {
+            let entry = map.entry(allergen.clone()).or_insert_with(|| Vec::new());
+            entry.push(HashSet::from_iter(list.ingredients.iter().cloned()));
+        }
+    }
+
+    return map;
+}
+
+fn condense_sets(sets: &HashMap<String, Vec<HashSet<String>>>) -> HashMap<String, HashSet<String>> {
+    let mut condensed = HashMap::new();
+    for (allergen, possibilities) in sets.iter() {
+        let mut combined = possibilities[0].clone();
+        for possibility in possibilities {
+            combined = combined.intersection(possibility).cloned().collect();
+        }
+
+        condensed.insert(allergen.clone(), combined);
+    }
+
+    return condensed;
+}
+
+fn find_single_values(sets: &HashMap<String, HashSet<String>>) -> Vec<String> {
+    let mut singles = Vec::new();
+    for set in sets.values() {
+        if set.len() == 1 {
+            singles.push(set.iter().next().unwrap().clone());
+        }
+    }
+
+    return singles;
+}
+
+fn reduce(sets: &mut HashMap<String, HashSet<String>>) -> Result<(), Error> {
+    if let Some((allergen, _)) = sets.iter().find(|(_, set)| set.is_empty()) {
+        return Err(Error::NoIngredientFor(allergen.clone()));
+    }
+
+    let mut singles = find_single_values(sets);
+    debug!("Original: {:?}", sets);
+    while singles.len() != sets.len() {
+        for set in sets.values_mut() {
+            if set.len() != 1 {
+                for single in &singles {
+                    set.remove(single);
+                }
+            }
+        }
+        trace!("Reduce: {:?}", sets);
+
+        let new_singles = find_single_values(sets);
+        if new_singles.len() == singles.len() {
+            return Err(Error::Ambiguous);
+        }
+        singles = new_singles;
+    }
+
+    return Ok(());
+}
+
+fn get_allergen_ingredients(sets: &HashMap<String, HashSet<String>>) -> HashSet<String> {
+    let mut ingredients = HashSet::new();
+    for s in sets.values() {
+        ingredients.insert(s.iter().next().unwrap().clone());
+    }
+
+    return ingredients;
+}
+
+fn count_ingredients(allergen_ingredients: &HashSet<String>, ingredient_lists: &Vec<IngredientList>) -> usize {
+    let mut count = 0;
+    for list in ingredient_lists {
+        count += list.ingredients.iter().filter(|i| !allergen_ingredients.contains(*i)).count();
+    }
+
+    return count;
+}
+
+pub fn solve(ingredient_lists: &Vec<IngredientList>) -> Result<usize, Error> {
+    return Ok(solve_with_details(ingredient_lists)?.0);
+}
+
+// The count along with the ingredients that can't contain an allergen.
+pub fn solve_with_details(ingredient_lists: &Vec<IngredientList>) -> Result<(usize, Value), Error> {
+    let allergen_sets = make_allergen_sets(ingredient_lists);
+
+    let mut condensed = condense_sets(&allergen_sets);
+    reduce(&mut condensed)?;
+
+    let allergen_ingredients = get_allergen_ingredients(&condensed);
+
+    let mut safe: Vec<&String> = ingredient_lists.iter().flat_map(|list| &list.ingredients).filter(|i| !allergen_ingredients.contains(*i)).collect();
+    safe.sort();
+    safe.dedup();
+    return Ok((count_ingredients(&allergen_ingredients, ingredient_lists), json!({ "safe_ingredients": safe })));
+}
+
+pub fn part1(input: &Input) -> Result<usize, Error> {
+    let ingredient_lists = parse_file(input)?;
+    return solve(&ingredient_lists);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let ingredient_lists = parse_file(input)?;
+    return solve_with_details(&ingredient_lists);
+}
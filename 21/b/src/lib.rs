@@ -0,0 +1,169 @@
+use std::error;
+use std::fmt;
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::{debug, trace};
+
+#[derive(Debug)]
+pub struct IngredientList {
+    ingredients: Vec<String>,
+    allergens: Vec<String>
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    NoIngredientFor(String),
+    Ambiguous,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::NoIngredientFor(allergen) => write!(f, "No ingredient appears in every food containing {}", allergen),
+            Error::Ambiguous => write!(f, "The foods don't narrow every allergen down to a single ingredient"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_line(line_number: usize, s: &str) -> Result<IngredientList, Error> {
+    let contains_location = match s.find("(contains") {
+        Some(location) if s.ends_with(')') => location,
+        _ => return Err(Error::Malformed { line: line_number, text: String::from(s), expected: "\"<ingredients> (contains <allergen>, <allergen>, ...)\"" })
+    };
+
+    let (ingredients, allergens) = s.split_at(contains_location);
+
+    let i = ingredients.trim().split(" ");
+    let a = allergens[9..(allergens.len() - 1)].trim().split(", ");
+
+    return Ok(IngredientList {
+        ingredients: i.map(|s| String::from(s)).collect(),
+        allergens: a.map(|s| String::from(s)).collect(),
+    });
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<IngredientList>, Error> {
+    return aoc_common::parse_lines_with(input, parse_line);
+}
+
+fn make_allergen_sets(lists: &Vec<IngredientList>) -> HashMap<String, Vec<HashSet<String>>> {
+    let mut map = HashMap::new();
+
+    for list in lists {
+        for allergen in &list.allergens {
+            let entry = map.entry(allergen.clone()).or_insert_with(|| Vec::new());
+            entry.push(HashSet::from_iter(list.ingredients.iter().cloned()));
+        }
+    }
+
+    return map;
+}
+
+fn condense_sets(sets: &HashMap<String, Vec<HashSet<String>>>) -> HashMap<String, HashSet<String>> {
+    let mut condensed = HashMap::new();
+    for (allergen, possibilities) in sets.iter() {
+        let mut combined = possibilities[0].clone();
+        for possibility in possibilities {
+            combined = combined.intersection(possibility).cloned().collect();
+        }
+
+        condensed.insert(allergen.clone(), combined);
+    }
+
+    return condensed;
+}
+
+fn find_single_values(sets: &HashMap<String, HashSet<String>>) -> Vec<String> {
+    let mut singles = Vec::new();
+    for set in sets.values() {
+        if set.len() == 1 {
+            singles.push(set.iter().next().unwrap().clone());
+        }
+    }
+
+    return singles;
+}
+
+fn reduce(sets: &mut HashMap<String, HashSet<String>>) -> Result<(), Error> {
+    if let Some((allergen, _)) = sets.iter().find(|(_, set)| set.is_empty()) {
+        return Err(Error::NoIngredientFor(allergen.clone()));
+    }
+
+    let mut singles = find_single_values(sets);
+    debug!("Original: {:?}", sets);
+    while singles.len() != sets.len() {
+        for set in sets.values_mut() {
+            if set.len() != 1 {
+                for single in &singles {
+                    set.remove(single);
+                }
+            }
+        }
+        trace!("Reduce: {:?}", sets);
+
+        let new_singles = find_single_values(sets);
+        if new_singles.len() == singles.len() {
+            return Err(Error::Ambiguous);
+        }
+        singles = new_singles;
+    }
+
+    return Ok(());
+}
+
+fn get_allergen_ingredients(sets: &HashMap<String, HashSet<String>>) -> Vec<(String, String)> {
+    let mut ingredients = Vec::new();
+    for (k, s) in sets.iter() {
+        ingredients.push((k.clone(), s.iter().next().unwrap().clone()));
+    }
+
+    return ingredients;
+}
+
+pub fn solve(ingredient_lists: &Vec<IngredientList>) -> Result<String, Error> {
+    return Ok(solve_with_details(ingredient_lists)?.0);
+}
+
+// The canonical list along with which allergen each of its ingredients contains.
+pub fn solve_with_details(ingredient_lists: &Vec<IngredientList>) -> Result<(String, Value), Error> {
+    let allergen_sets = make_allergen_sets(ingredient_lists);
+
+    let mut condensed = condense_sets(&allergen_sets);
+    reduce(&mut condensed)?;
+
+    let mut allergen_ingredients = get_allergen_ingredients(&condensed);
+    allergen_ingredients.sort_by(|(aa, _ai), (ba, _bi)| aa.partial_cmp(ba).unwrap());
+    let ingredients: Vec<Value> = allergen_ingredients.iter().map(|(a, i)| json!({ "allergen": a, "ingredient": i })).collect();
+    return Ok((allergen_ingredients.iter().map(|(_a, i)| i.clone()).collect::<Vec<String>>().join(","), json!({ "ingredients": ingredients })));
+}
+
+pub fn part2(input: &Input) -> Result<String, Error> {
+    let ingredient_lists = parse_file(input)?;
+    return solve(&ingredient_lists);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(String, Value), Error> {
+    let ingredient_lists = parse_file(input)?;
+    return solve_with_details(&ingredient_lists);
+}
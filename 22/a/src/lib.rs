@@ -0,0 +1,138 @@
+use std::error;
+use std::fmt;
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{json, Input, InputError, Record, Value};
+use log::info;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    MissingDeck(&'static str),
+    DuplicateCard(i64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::MissingDeck(player) => write!(f, "The input is missing the deck for {}", player),
+            Error::DuplicateCard(card) => write!(f, "Card {} is dealt more than once", card),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_deck(record: Option<&Record>, header: &'static str) -> Result<VecDeque<i64>, Error> {
+    let record = record.ok_or(Error::MissingDeck(&header[..header.len() - 1]))?;
+    if record.lines[0] != header {
+        return Err(Error::Malformed { line: record.line, text: String::from(&record.lines[0]), expected: header });
+    }
+
+    let mut deck = VecDeque::new();
+    for (offset, line) in record.lines.iter().enumerate().skip(1) {
+        match line.trim().parse::<i64>() {
+            Err(_) => return Err(Error::Malformed { line: record.line + offset, text: String::from(line), expected: "a card number" }),
+            Ok(card) => deck.push_back(card),
+        }
+    }
+
+    return Ok(deck);
+}
+
+pub fn parse_file(input: &Input) -> Result<(VecDeque<i64>, VecDeque<i64>), Error> {
+    let records = aoc_common::read_records(input)?;
+    let deck1 = parse_deck(records.first(), "Player 1:")?;
+    let deck2 = parse_deck(records.get(1), "Player 2:")?;
+    return Ok((deck1, deck2));
+}
+
+fn play(deck1: &mut VecDeque<i64>,deck2: &mut VecDeque<i64>) -> i64 {
+    while !(deck1.is_empty() || deck2.is_empty()) {
+        let top1 = deck1.pop_front().unwrap();
+        let top2 = deck2.pop_front().unwrap();
+
+        assert!(top1 != top2);
+
+        if top1 > top2 {
+            deck1.push_back(top1);
+            deck1.push_back(top2);
+        } else {
+            deck2.push_back(top2);
+            deck2.push_back(top1);
+        }
+    }
+
+    if deck1.is_empty() {
+        return score(deck2);
+    } else {
+        return score(deck1);
+    }
+}
+
+fn score(deck: &VecDeque<i64>) -> i64 {
+    let mut multiplier = deck.len() as i64;
+    let mut s = 0;
+    for i in 0..deck.len() {
+        s += *deck.get(i).unwrap() * multiplier;
+        multiplier -= 1;
+    }
+
+    return s;
+}
+
+// Every card has to be unique, otherwise a round can end in a tie.
+fn check_cards(player1_deck: &VecDeque<i64>, player2_deck: &VecDeque<i64>) -> Result<(), Error> {
+    let mut seen = HashSet::new();
+    for card in player1_deck.iter().chain(player2_deck.iter()) {
+        if !seen.insert(*card) {
+            return Err(Error::DuplicateCard(*card));
+        }
+    }
+
+    return Ok(());
+}
+
+pub fn solve(decks: &(VecDeque<i64>, VecDeque<i64>)) -> Result<i64, Error> {
+    return Ok(solve_with_details(decks)?.0);
+}
+
+// The score along with who won and the deck they won with.
+pub fn solve_with_details((player1_deck, player2_deck): &(VecDeque<i64>, VecDeque<i64>)) -> Result<(i64, Value), Error> {
+    check_cards(player1_deck, player2_deck)?;
+
+    info!("Player 1: {:?}", player1_deck);
+    info!("Player 2: {:?}", player2_deck);
+
+    let mut player1_deck = player1_deck.clone();
+    let mut player2_deck = player2_deck.clone();
+    let final_score = play(&mut player1_deck, &mut  player2_deck);
+    let (winner, deck) = if player2_deck.is_empty() { (1, player1_deck) } else { (2, player2_deck) };
+    return Ok((final_score, json!({ "winner": winner, "deck": deck })));
+}
+
+pub fn part1(input: &Input) -> Result<i64, Error> {
+    let decks = parse_file(input)?;
+    return solve(&decks);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let decks = parse_file(input)?;
+    return solve_with_details(&decks);
+}
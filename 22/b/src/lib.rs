@@ -0,0 +1,151 @@
+use std::error;
+use std::fmt;
+use std::collections::{HashSet, VecDeque};
+use std::iter::FromIterator;
+
+use aoc_common::{json, Input, InputError, Record, Value};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    MissingDeck(&'static str),
+    DuplicateCard(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::MissingDeck(player) => write!(f, "The input is missing the deck for {}", player),
+            Error::DuplicateCard(card) => write!(f, "Card {} is dealt more than once", card),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_deck(record: Option<&Record>, header: &'static str) -> Result<VecDeque<usize>, Error> {
+    let record = record.ok_or(Error::MissingDeck(&header[..header.len() - 1]))?;
+    if record.lines[0] != header {
+        return Err(Error::Malformed { line: record.line, text: String::from(&record.lines[0]), expected: header });
+    }
+
+    let mut deck = VecDeque::new();
+    for (offset, line) in record.lines.iter().enumerate().skip(1) {
+        match line.trim().parse::<usize>() {
+            Err(_) => return Err(Error::Malformed { line: record.line + offset, text: String::from(line), expected: "a card number" }),
+            Ok(card) => deck.push_back(card),
+        }
+    }
+
+    return Ok(deck);
+}
+
+pub fn parse_file(input: &Input) -> Result<(VecDeque<usize>, VecDeque<usize>), Error> {
+    let records = aoc_common::read_records(input)?;
+    let deck1 = parse_deck(records.first(), "Player 1:")?;
+    let deck2 = parse_deck(records.get(1), "Player 2:")?;
+    return Ok((deck1, deck2));
+}
+
+// returns true if player 1 wins
+fn play(deck1: &mut VecDeque<usize>,deck2: &mut VecDeque<usize>) -> bool {
+    let mut previous_decks = Vec::new();
+    while !(deck1.is_empty() || deck2.is_empty()) {
+        for (d1, d2) in previous_decks.iter() {
+            if d1 == deck1 && d2 == deck2 {
+                return true;
+            }
+        }
+
+        previous_decks.push((deck1.clone(), deck2.clone()));
+
+        
+        let top1 = deck1.pop_front().unwrap();
+        let top2 = deck2.pop_front().unwrap();
+        
+        assert!(top1 != top2);
+
+        let mut player1_wins = top1 > top2;
+        if deck1.len() >= top1 && deck2.len() >= top2{
+            let mut new_deck1 = VecDeque::from_iter(deck1.iter().take(top1).cloned());
+            let mut new_deck2 = VecDeque::from_iter(deck2.iter().take(top2).cloned());
+
+            player1_wins = play(&mut new_deck1, &mut new_deck2);
+        }
+
+        if player1_wins {
+            deck1.push_back(top1);
+            deck1.push_back(top2);
+        } else {
+            deck2.push_back(top2);
+            deck2.push_back(top1);
+        }
+    }
+
+    return deck2.is_empty();
+}
+
+fn score(deck: &VecDeque<usize>) -> usize {
+    let mut multiplier = deck.len();
+    let mut s = 0;
+    for i in 0..deck.len() {
+        s += *deck.get(i).unwrap() * multiplier;
+        multiplier -= 1;
+    }
+
+    return s;
+}
+
+// Every card has to be unique, otherwise a round can end in a tie.
+fn check_cards(player1_deck: &VecDeque<usize>, player2_deck: &VecDeque<usize>) -> Result<(), Error> {
+    let mut seen = HashSet::new();
+    for card in player1_deck.iter().chain(player2_deck.iter()) {
+        if !seen.insert(*card) {
+            return Err(Error::DuplicateCard(*card));
+        }
+    }
+
+    return Ok(());
+}
+
+pub fn solve(decks: &(VecDeque<usize>, VecDeque<usize>)) -> Result<usize, Error> {
+    return Ok(solve_with_details(decks)?.0);
+}
+
+// The score along with who won and the deck they won with.
+pub fn solve_with_details((player1_deck, player2_deck): &(VecDeque<usize>, VecDeque<usize>)) -> Result<(usize, Value), Error> {
+    check_cards(player1_deck, player2_deck)?;
+
+    let mut player1_deck = player1_deck.clone();
+    let mut player2_deck = player2_deck.clone();
+
+    let player1_wins = play(&mut player1_deck, &mut  player2_deck);
+    let (winner, deck) = if player1_wins { (1, player1_deck) } else { (2, player2_deck) };
+    return Ok((score(&deck), json!({ "winner": winner, "deck": deck })));
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let decks = parse_file(input)?;
+    return solve(&decks);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let decks = parse_file(input)?;
+    return solve_with_details(&decks);
+}
@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::{info, trace};
+
+fn rotate_to(target: usize, queue: &mut VecDeque<usize>) {
+    while *queue.front().unwrap() != target {
+        queue.rotate_left(1);
+    }
+}
+
+fn play(input: Vec<usize>) -> VecDeque<usize> {
+    let mut cups = VecDeque::from(input);
+
+    for i in 0..100 {
+        let current_cup = cups.pop_front().unwrap();
+        let mut rest = cups.split_off(3);
+
+        let mut destination_cup = current_cup - 1;
+        if destination_cup == 0 {
+            destination_cup = 9;
+        }
+
+        while cups.contains(&destination_cup) {
+            destination_cup = destination_cup - 1;
+            if destination_cup == 0 {
+                destination_cup = 9;
+            }    
+        }
+
+        trace!("Move {}: current {}, pick up {:?}, destination {}", i + 1, current_cup, cups, destination_cup);
+        rest.push_front(current_cup);
+        rotate_to(destination_cup, &mut rest);
+        rest.rotate_left(1);
+
+        for cup in cups.iter().rev() {
+            rest.push_front(*cup);
+        }
+
+        rotate_to(current_cup, &mut rest);
+        rest.rotate_left(1);
+
+        cups = rest;
+    }
+
+    rotate_to(1, &mut cups);
+
+    return cups;
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    LineCount(usize),
+    InvalidCups(Vec<usize>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::LineCount(count) => write!(f, "Expected a single line of cup labels, found {} lines", count),
+            Error::InvalidCups(cups) => write!(f, "The cups {:?} aren't the labels 1 to 9 each used once", cups),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+// The game only works when the cups are labelled 1 to 9 with no repeats.
+fn check_cups(starting_cups: &[usize]) -> Result<(), Error> {
+    let mut sorted = starting_cups.to_vec();
+    sorted.sort();
+    if sorted != (1..=9).collect::<Vec<usize>>() {
+        return Err(Error::InvalidCups(starting_cups.to_vec()));
+    }
+
+    return Ok(());
+}
+
+fn parse_cups(line_number: usize, line: &str) -> Result<Vec<usize>, Error> {
+    let mut cups = Vec::new();
+    for label in line.trim().chars() {
+        match label.to_digit(10) {
+            None => return Err(Error::Malformed { line: line_number, text: String::from(line), expected: "a line of cup labels like 389125467" }),
+            Some(label) => cups.push(label as usize),
+        }
+    }
+
+    return Ok(cups);
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<usize>, Error> {
+    let mut lines = aoc_common::parse_lines_with(input, parse_cups)?;
+    if lines.len() != 1 {
+        return Err(Error::LineCount(lines.len()));
+    }
+
+    return Ok(lines.remove(0));
+}
+
+pub fn solve(starting_cups: &Vec<usize>) -> Result<String, Error> {
+    return Ok(solve_with_details(starting_cups)?.0);
+}
+
+// The labels along with the order of all the cups, starting at cup 1.
+pub fn solve_with_details(starting_cups: &Vec<usize>) -> Result<(String, Value), Error> {
+    check_cups(starting_cups)?;
+
+    let end = play(starting_cups.to_vec());
+    info!("Final cups: {:?}", end);
+
+    return Ok((end.iter().skip(1).map(|cup| cup.to_string()).collect(), json!({ "cups": end })));
+}
+
+pub fn part1(input: &Input) -> Result<String, Error> {
+    let starting_cups = parse_file(input)?;
+    return solve(&starting_cups);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(String, Value), Error> {
+    let starting_cups = parse_file(input)?;
+    return solve_with_details(&starting_cups);
+}
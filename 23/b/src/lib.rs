@@ -0,0 +1,149 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::{info, trace};
+
+fn play(input: Vec<usize>) -> Vec<usize> {
+    let mut next = vec![0;input.len() + 1];
+    let last = input.len() - 1;
+    for i in 0..last {
+        next[input[i]] = input[i + 1];
+    }
+    next[input[last]] = input[0];
+
+    let mut current_cup = input[last];
+
+    for i in 0..10000000 {
+        current_cup = next[current_cup];
+
+        let next1 = next[current_cup];
+        let next2 = next[next1];
+        let next3 = next[next2];
+
+        let mut destination_cup = current_cup - 1;
+        if destination_cup == 0 {
+            destination_cup = input.len();
+        }
+
+        while destination_cup == next1 || destination_cup == next2 || destination_cup == next3 {
+            destination_cup = destination_cup - 1;
+            if destination_cup == 0 {
+                destination_cup = input.len();
+            }    
+        }
+
+        trace!("Move {}: current {}, pick up {}, {}, {}, destination {}", i + 1, current_cup, next1, next2, next3, destination_cup);
+
+        next[current_cup] = next[next3];
+        next[next3] = next[destination_cup];
+        next[destination_cup] = next1;
+    }
+
+    return next;
+}
+
+fn format_cups(cups: &Vec<usize>, count: usize) -> String {
+    let mut labels = Vec::new();
+    let mut current_cup = 1;
+    for _ in 0..count {
+        labels.push(cups[current_cup].to_string());
+        current_cup = cups[current_cup];
+    }
+
+    return labels.join(" ");
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    LineCount(usize),
+    InvalidCups(Vec<usize>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::LineCount(count) => write!(f, "Expected a single line of cup labels, found {} lines", count),
+            Error::InvalidCups(cups) => write!(f, "The cups {:?} aren't the labels 1 to 9 each used once", cups),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+// The game only works when the cups are labelled 1 to 9 with no repeats.
+fn check_cups(starting_cups: &[usize]) -> Result<(), Error> {
+    let mut sorted = starting_cups.to_vec();
+    sorted.sort();
+    if sorted != (1..=9).collect::<Vec<usize>>() {
+        return Err(Error::InvalidCups(starting_cups.to_vec()));
+    }
+
+    return Ok(());
+}
+
+fn parse_cups(line_number: usize, line: &str) -> Result<Vec<usize>, Error> {
+    let mut cups = Vec::new();
+    for label in line.trim().chars() {
+        match label.to_digit(10) {
+            None => return Err(Error::Malformed { line: line_number, text: String::from(line), expected: "a line of cup labels like 389125467" }),
+            Some(label) => cups.push(label as usize),
+        }
+    }
+
+    return Ok(cups);
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<usize>, Error> {
+    let mut lines = aoc_common::parse_lines_with(input, parse_cups)?;
+    if lines.len() != 1 {
+        return Err(Error::LineCount(lines.len()));
+    }
+
+    return Ok(lines.remove(0));
+}
+
+pub fn solve(starting_cups: &Vec<usize>) -> Result<usize, Error> {
+    return Ok(solve_with_details(starting_cups)?.0);
+}
+
+// The product along with the two cups that make it.
+pub fn solve_with_details(starting_cups: &Vec<usize>) -> Result<(usize, Value), Error> {
+    check_cups(starting_cups)?;
+
+    let mut input = starting_cups.to_vec();
+    for i in 10..=1000000 {
+        input.push(i);
+    }
+    let cups = play(input);
+    info!("Cups after 1: {}", format_cups(&cups, 8));
+
+    return Ok((cups[1] * cups[cups[1]], json!({ "cups_after_one": [cups[1], cups[cups[1]]] })));
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let starting_cups = parse_file(input)?;
+    return solve(&starting_cups);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let starting_cups = parse_file(input)?;
+    return solve_with_details(&starting_cups);
+}
@@ -0,0 +1,107 @@
+use std::error;
+use std::fmt;
+use std::collections::HashMap;
+
+use aoc_common::{json, Input, InputError, Value};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+const DIRECTIONS_FORM: &str = "directions made of \"e\", \"se\", \"sw\", \"w\", \"nw\" and \"ne\"";
+
+fn parse_line(line_number: usize, s: &str) -> Result<(i64, i64), Error> {
+    let mut position = (0, 0);
+    let chars = s.chars().collect::<Vec<char>>();
+
+    let mut current_index = 0;
+    while current_index < chars.len() {
+        match chars[current_index] {
+            'e' => position = (position.0 - 2, position.1),
+            'w' => position = (position.0 + 2, position.1),
+            's' => {
+                current_index += 1;
+                match chars.get(current_index) {
+                    Some('e') => position = (position.0 - 1, position.1 - 1),
+                    Some('w') => position = (position.0 + 1, position.1 - 1),
+                    _ => return Err(Error::Malformed { line: line_number, text: String::from(s), expected: DIRECTIONS_FORM }),
+                }
+            },
+            'n' => {
+                current_index += 1;
+                match chars.get(current_index) {
+                    Some('e') => position = (position.0 - 1, position.1 + 1),
+                    Some('w') => position = (position.0 + 1, position.1 + 1),
+                    _ => return Err(Error::Malformed { line: line_number, text: String::from(s), expected: DIRECTIONS_FORM }),
+                }
+            },
+            _ => return Err(Error::Malformed { line: line_number, text: String::from(s), expected: DIRECTIONS_FORM }),
+        }
+        current_index += 1;
+    }
+
+    return Ok(position);
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<(i64, i64)>, Error> {
+    return aoc_common::parse_lines_with(input, parse_line);
+}
+
+pub fn solve(flips: &Vec<(i64, i64)>) -> Result<usize, Error> {
+    return Ok(solve_with_details(flips)?.0);
+}
+
+// The black tiles along with how many flips there were and how many different tiles they touched.
+pub fn solve_with_details(flips: &Vec<(i64, i64)>) -> Result<(usize, Value), Error> {
+    let mut tiles = HashMap::new();
+
+    for flip in flips {
+        if tiles.contains_key(flip) {
+            if *tiles.get(flip).unwrap() == "black" {
+                tiles.insert(*flip, "white");
+            } else {
+                tiles.insert(*flip, "black");
+            }
+        } else {
+            tiles.insert(*flip, "black");
+        }
+    }
+
+    return Ok((tiles.values().filter(|x| **x == "black").count(), json!({ "flips": flips.len(), "tiles_flipped": tiles.len() })));
+}
+
+pub fn part1(input: &Input) -> Result<usize, Error> {
+    let flips = parse_file(input)?;
+    return solve(&flips);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let flips = parse_file(input)?;
+    return solve_with_details(&flips);
+}
@@ -0,0 +1,167 @@
+use std::error;
+use std::fmt;
+use std::collections::HashMap;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::debug;
+
+pub type Tile = (i64, i64);
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+const DIRECTIONS_FORM: &str = "directions made of \"e\", \"se\", \"sw\", \"w\", \"nw\" and \"ne\"";
+
+fn parse_line(line_number: usize, s: &str) -> Result<Tile, Error> {
+    let mut position = (0, 0);
+    let chars = s.chars().collect::<Vec<char>>();
+
+    let mut current_index = 0;
+    while current_index < chars.len() {
+        match chars[current_index] {
+            'e' => position = (position.0 - 2, position.1),
+            'w' => position = (position.0 + 2, position.1),
+            's' => {
+                current_index += 1;
+                match chars.get(current_index) {
+                    Some('e') => position = (position.0 - 1, position.1 - 1),
+                    Some('w') => position = (position.0 + 1, position.1 - 1),
+                    _ => return Err(Error::Malformed { line: line_number, text: String::from(s), expected: DIRECTIONS_FORM }),
+                }
+            },
+            'n' => {
+                current_index += 1;
+                match chars.get(current_index) {
+                    Some('e') => position = (position.0 - 1, position.1 + 1),
+                    Some('w') => position = (position.0 + 1, position.1 + 1),
+                    _ => return Err(Error::Malformed { line: line_number, text: String::from(s), expected: DIRECTIONS_FORM }),
+                }
+            },
+            _ => return Err(Error::Malformed { line: line_number, text: String::from(s), expected: DIRECTIONS_FORM }),
+        }
+        current_index += 1;
+    }
+
+    return Ok(position);
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Tile>, Error> {
+    return aoc_common::parse_lines_with(input, parse_line);
+}
+
+fn insert_tile(tile: Tile, floor: &mut HashMap<Tile, &str>) {
+    floor.entry(tile).or_insert("white");
+}
+
+fn flip_tile(tile: Tile, mut floor: &mut HashMap<Tile, &str>) {
+    ensure_tile_border_exists(tile, &mut floor);
+    if *floor.get(&tile).unwrap() == "black" {
+        floor.insert(tile, "white");
+    } else {
+        floor.insert(tile, "black");
+    }
+}
+
+const TILE_WITH_BORDER: [Tile; 7] = [(0,0), (1,1), (2,0), (1,-1), (-1,-1), (-2,0), (-1,1)];
+fn ensure_tile_border_exists(tile: Tile, mut floor: &mut HashMap<Tile, &str>) {
+    for offset in TILE_WITH_BORDER.iter() {
+        insert_tile((tile.0 + offset.0, tile.1 + offset.1), &mut floor);
+    }
+}
+
+
+fn count_black_border(tile: Tile, floor: &HashMap<Tile, &str>) -> usize {
+    let mut count = 0;
+    for offset in TILE_WITH_BORDER.iter() {
+        if *offset == (0,0) {
+            continue;
+        }
+
+        let target = (tile.0 + offset.0, tile.1 + offset.1);
+
+        if let Some(&value) = floor.get(&target) {
+            if value == "black" {
+                count += 1;
+            }
+        }
+    }
+
+    return count;
+}
+
+fn tick(mut floor: &mut HashMap<Tile, &str>) {
+    let mut tiles_to_flip = Vec::new();
+
+    for (&tile, &value) in floor.iter() {
+        let black_count = count_black_border(tile, &floor);
+        let black_to_white = value == "black" && (black_count == 0 || black_count > 2);
+        let white_to_black = value == "white" && (black_count == 2);
+        if black_to_white || white_to_black {
+            tiles_to_flip.push(tile);
+        }
+    }
+
+    for tile in tiles_to_flip {
+        flip_tile(tile, &mut floor);
+    }
+}
+
+pub fn solve(flips: &Vec<Tile>) -> Result<usize, Error> {
+    return Ok(solve_with_details(flips)?.0);
+}
+
+// The black tiles along with how many there were at the end of each day.
+pub fn solve_with_details(flips: &Vec<Tile>) -> Result<(usize, Value), Error> {
+    let mut tiles = HashMap::new();
+
+    for flip in flips {
+        flip_tile(*flip, &mut tiles);
+    }
+
+    let mut by_day = Vec::new();
+    for i in 0..100 {
+        tick(&mut tiles);
+        let black_tiles = tiles.values().filter(|x| **x == "black").count();
+        debug!("Day {}: {}", i + 1, black_tiles);
+        by_day.push(black_tiles);
+    }
+
+    return Ok((tiles.values().filter(|x| **x == "black").count(), json!({ "black_tiles_by_day": by_day })));
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let flips = parse_file(input)?;
+    return solve(&flips);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let flips = parse_file(input)?;
+    return solve_with_details(&flips);
+}
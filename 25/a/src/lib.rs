@@ -0,0 +1,106 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use log::info;
+
+const PUBLIC_SUBJECT_NUMBER: i64 = 7;
+const DIVISOR: i64 = 20201227;
+
+// 7^k mod 20201227 = 17807724
+// 7^j mod 20201227 = 5764801
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    KeyCount(usize),
+    NoLoopSize(i64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::KeyCount(count) => write!(f, "Expected the card's and the door's public keys, found {} numbers", count),
+            Error::NoLoopSize(public_key) => write!(f, "No loop size transforms {} into the public key {}", PUBLIC_SUBJECT_NUMBER, public_key),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+// The card's public key followed by the door's, on separate lines or the same one.
+pub fn parse_file(input: &Input) -> Result<(i64, i64), Error> {
+    let mut keys = Vec::new();
+    for (index, line) in aoc_common::read_lines(input)?.iter().enumerate() {
+        for key in line.split_whitespace() {
+            match key.parse::<i64>() {
+                Err(_) => return Err(Error::Malformed { line: index + 1, text: String::from(line), expected: "public keys" }),
+                Ok(key) => keys.push(key),
+            }
+        }
+    }
+
+    if keys.len() != 2 {
+        return Err(Error::KeyCount(keys.len()));
+    }
+
+    return Ok((keys[0], keys[1]));
+}
+
+pub fn solve(public_keys: &(i64, i64)) -> Result<i64, Error> {
+    return Ok(solve_with_details(public_keys)?.0);
+}
+
+// The encryption key along with the card's loop size.
+pub fn solve_with_details(&(card_public_key, door_public_key): &(i64, i64)) -> Result<(i64, Value), Error> {
+
+    let mut loop_counter = 0;
+    let mut current_value = 1;
+
+    while current_value != card_public_key {
+        // The values cycle after at most DIVISOR - 1 steps, so the key can't be reached after that.
+        if loop_counter >= DIVISOR {
+            return Err(Error::NoLoopSize(card_public_key));
+        }
+
+        current_value *= PUBLIC_SUBJECT_NUMBER;
+        current_value %= DIVISOR;
+        loop_counter += 1;
+    }
+
+    info!("Card loop size: {}", loop_counter);
+
+    let mut current_encryption_value = 1;
+    for _ in 0..loop_counter {
+        current_encryption_value *= door_public_key;
+        current_encryption_value %= DIVISOR;
+    }
+
+    return Ok((current_encryption_value, json!({ "card_loop_size": loop_counter })));
+}
+
+pub fn part1(input: &Input) -> Result<i64, Error> {
+    let public_keys = parse_file(input)?;
+    return solve(&public_keys);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let public_keys = parse_file(input)?;
+    return solve_with_details(&public_keys);
+}
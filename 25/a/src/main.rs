@@ -1,29 +1,21 @@
-const PUBLIC_SUBJECT_NUMBER: i64 = 7;
-const DOOR_PUBLIC_KEY: i64 = 17115212;
-const CARD_PUBLIC_KEY: i64 = 3667832;
-const DIVISOR: i64 = 20201227;
+use std::time::Instant;
 
-// 7^k mod 20201227 = 17807724
-// 7^j mod 20201227 = 5764801
+use day25a::part1_with_details;
 
 fn main() {
+    let options = aoc_common::cli::parse_args();
 
-    let mut loop_counter = 0;
-    let mut current_value = 1;
+    let start = Instant::now();
+    let (encryption_key, mut details) = match part1_with_details(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(found) => found
+    };
 
-    while current_value != CARD_PUBLIC_KEY {
-        current_value *= PUBLIC_SUBJECT_NUMBER;
-        current_value %= DIVISOR;
-        loop_counter += 1;
+    if options.json {
+        details["input"] = aoc_common::json!(options.input.to_string());
+        details["description"] = aoc_common::json!("Encryption key");
+        aoc_common::output::print_json(25, 1, &encryption_key, start.elapsed(), details);
+    } else {
+        println!("Encryption Key: {}", encryption_key);
     }
-
-    println!("Card counter: {}", loop_counter);
-
-    let mut current_encryption_value = 1;
-    for i in 0..loop_counter {
-        current_encryption_value *= DOOR_PUBLIC_KEY;
-        current_encryption_value %= DIVISOR;
-    }
-
-    println!("Encryption Key: {}", current_encryption_value);
 }
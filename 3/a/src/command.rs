@@ -0,0 +1,158 @@
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+use aoc_common::cli::DayOption;
+use aoc_common::{json, Value};
+
+use crate::render::{encode, image_format, render};
+use crate::{best_run, every_slope, parse_file, parse_slope, run_all, trees_json, Goal, Run, Slope};
+
+pub const OPTIONS: &[DayOption] = &[
+    DayOption { name: "slopes", value: Some("\"<dx>,<dy> ...\""), description: "Multiply the trees hit on these slopes instead, dx may be negative" },
+    DayOption { name: "fewest", value: Some("<max dx>,<max dy>"), description: "Find the slope up to these steps that hits the fewest trees" },
+    DayOption { name: "most", value: Some("<max dx>,<max dy>"), description: "Find the slope up to these steps that hits the most trees" },
+    DayOption { name: "collisions", value: None, description: "List the trees hit on each slope" },
+    DayOption { name: "render", value: None, description: "Draw the path of each slope over the map, marked with O and X" },
+    DayOption { name: "image", value: Some("<file.ppm|file.pgm>"), description: "Draw the path of the only slope to a PPM or PGM image" },
+];
+
+fn parse_slopes(text: &str) -> Vec<Slope> {
+    let mut slopes = Vec::new();
+    for slope in text.split_whitespace() {
+        match parse_slope(slope) {
+            None => aoc_common::exit_with_error(format!("Couldn't parse the slope '{}': expected \"<dx>,<dy>\"", slope)),
+            Some(slope) => slopes.push(slope),
+        }
+    }
+    if slopes.is_empty() {
+        aoc_common::exit_with_error("--slopes expects at least one slope");
+    }
+
+    return slopes;
+}
+
+// The slopes to search, listed up front so bounds too large to try are turned down before the map is read.
+fn parse_bounds(text: &str) -> Vec<Slope> {
+    let bounds = text.split_once(',').and_then(|(max_dx, max_dy)| Some((max_dx.trim().parse().ok()?, max_dy.trim().parse().ok()?)));
+    let (max_dx, max_dy) = match bounds {
+        Some((max_dx, max_dy)) if max_dy > 0 => (max_dx, max_dy),
+        _ => aoc_common::exit_with_error(format!("Couldn't parse the bounds '{}': expected \"<max dx>,<max dy>\" going at least 1 down", text)),
+    };
+
+    return match every_slope(max_dx, max_dy) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(slopes) => slopes
+    };
+}
+
+fn format_run(run: &Run) -> String {
+    let collisions: Vec<String> = run.collisions.iter().map(|(x, y)| format!("({},{})", x, y)).collect();
+    return format!("Slope {}: {} trees at {}", run.slope, run.collisions.len(), collisions.join(" "));
+}
+
+fn run_json(run: &Run) -> Value {
+    return json!({ "slope": run.slope.to_string(), "trees": run.collisions.len(), "collisions": run.collisions });
+}
+
+pub fn main(part: usize, default_slopes: &[Slope], default_description: &str) {
+    let options = aoc_common::cli::parse_args_with(OPTIONS);
+    let search = match (options.value("fewest"), options.value("most")) {
+        (Some(_), Some(_)) => aoc_common::exit_with_error("Expected only one of --fewest and --most"),
+        (Some(bounds), None) => Some((Goal::FewestTrees, bounds)),
+        (None, Some(bounds)) => Some((Goal::MostTrees, bounds)),
+        (None, None) => None,
+    };
+    let (slopes, description) = match (options.value("slopes"), search) {
+        (Some(_), Some(_)) => aoc_common::exit_with_error("--slopes can't be used with --fewest or --most"),
+        (Some(text), None) => (parse_slopes(text), format!("Product of the trees hit on the slopes {}", text.trim())),
+        // A search is described by the slope it finds, once it's found it.
+        (None, Some((_, bounds))) => (parse_bounds(bounds), String::new()),
+        (None, None) => (default_slopes.to_vec(), String::from(default_description)),
+    };
+
+    let image = options.value("image").map(|file_name| match image_format(Path::new(file_name)) {
+        None => aoc_common::exit_with_error(format!("Couldn't tell the format of the image '{}': expected a .ppm or .pgm file", file_name)),
+        Some(format) => (file_name, format),
+    });
+
+    let start = Instant::now();
+    let map = match parse_file(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(map) => map
+    };
+    let runs = match run_all(&map, &slopes) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(runs) => runs
+    };
+
+    // A search answers with the best slope's trees, otherwise the trees on every slope are multiplied.
+    let (answer, description, shown) = match search {
+        Some((goal, _)) => {
+            let best = match best_run(&runs, goal) {
+                None => aoc_common::exit_with_error("There are no slopes to search"),
+                Some(best) => best
+            };
+            let description = match goal {
+                Goal::FewestTrees => format!("Fewest trees hit, on slope {}", best.slope),
+                Goal::MostTrees => format!("Most trees hit, on slope {}", best.slope),
+            };
+            (best.collisions.len(), description, vec![best])
+        },
+        None => {
+            let product = runs.iter().try_fold(1usize, |product, run| product.checked_mul(run.collisions.len()));
+            let product = match product {
+                None => aoc_common::exit_with_error(crate::Error::TooManyTrees),
+                Some(product) => product
+            };
+            (product, description, runs.iter().collect())
+        },
+    };
+
+    let mut renderings = Vec::new();
+    if options.flag("render") || image.is_some() {
+        for run in &shown {
+            match render(&map, run.slope) {
+                Err(why) => aoc_common::exit_with_error(why),
+                Ok(rows) => renderings.push((run.slope, rows)),
+            }
+        }
+    }
+
+    if let Some((file_name, format)) = image {
+        if renderings.len() != 1 {
+            aoc_common::exit_with_error("--image draws a single slope, pick one with --slopes");
+        }
+        if let Err(why) = fs::write(file_name, encode(&renderings[0].1, format)) {
+            aoc_common::exit_with_error(format!("Couldn't write the image '{}': {}", file_name, why));
+        }
+    }
+
+    if options.json {
+        let mut details = json!({ "input": options.input.to_string(), "description": description, "slopes": trees_json(&shown) });
+        if options.flag("collisions") {
+            details["runs"] = shown.iter().map(|run| run_json(run)).collect();
+        }
+        if options.flag("render") {
+            details["renderings"] = renderings.iter().map(|(slope, rows)| json!({ "slope": slope.to_string(), "rows": rows })).collect();
+        }
+        aoc_common::output::print_json(3, part, &answer, start.elapsed(), details);
+    } else {
+        if options.flag("collisions") {
+            for run in &shown {
+                println!("{}", format_run(run));
+            }
+        }
+        if options.flag("render") {
+            for (slope, rows) in &renderings {
+                println!("Slope {}:", slope);
+                println!("{}", rows.join("\n"));
+            }
+        }
+        if search.is_some() {
+            println!("{}: {}", description, answer);
+        } else {
+            print!("Trees: {}", answer);
+        }
+    }
+}
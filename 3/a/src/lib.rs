@@ -0,0 +1,204 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+
+pub mod command;
+pub mod render;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    EmptyMap,
+    InvalidSlope(Slope),
+    TooManySlopes { max_dx: usize, max_dy: usize },
+    TooWide(Slope),
+    TooManyTrees,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::EmptyMap => write!(f, "The map is empty"),
+            Error::InvalidSlope(slope) => write!(f, "The slope {} doesn't go down the hill", slope),
+            Error::TooManySlopes { max_dx, max_dy } => write!(f, "There are too many slopes up to {},{} to try, the most is {}", max_dx, max_dy, MAX_SLOPES),
+            Error::TooWide(slope) => write!(f, "The path down the slope {} is too wide to draw", slope),
+            Error::TooManyTrees => write!(f, "The product of the trees is too large"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_space(element: char) -> Option<bool> {
+    return match element {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None
+    };
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Vec<bool>>, Error> {
+    return Ok(aoc_common::parse_grid(input, "'.' or '#'", parse_space)?);
+}
+
+// Right `dx` (left when it's negative) and down `dy` every step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slope {
+    pub dx: i64,
+    pub dy: usize,
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{},{}", self.dx, self.dy);
+    }
+}
+
+pub const SLOPE: Slope = Slope { dx: 3, dy: 1 };
+
+// Reads a slope written "<dx>,<dy>", as it's displayed.
+pub fn parse_slope(text: &str) -> Option<Slope> {
+    let (dx, dy) = text.trim().split_once(',')?;
+    return Some(Slope { dx: dx.trim().parse().ok()?, dy: dy.trim().parse().ok()? });
+}
+
+// The trees hit going down one slope, as (column, row) on the map. The map repeats to the left as well as the right.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub slope: Slope,
+    pub collisions: Vec<(usize, usize)>,
+}
+
+fn check_map(map: &Vec<Vec<bool>>) -> Result<(), Error> {
+    if map.is_empty() || map[0].is_empty() {
+        return Err(Error::EmptyMap);
+    }
+
+    return Ok(());
+}
+
+fn collisions(map: &Vec<Vec<bool>>, slope: Slope) -> Vec<(usize, usize)> {
+    let width = map[0].len() as i64;
+    // Going round the map whole times lands in the same column, so only the rest of the step matters.
+    let dx = slope.dx.rem_euclid(width);
+    let mut collisions = Vec::new();
+
+    let mut x: i64 = 0;
+    let mut y: usize = 0;
+    while y < map.len() {
+        if map[y][x as usize] {
+            collisions.push((x as usize, y));
+        }
+
+        y = y.saturating_add(slope.dy);
+        x = (x + dx) % width;
+    }
+
+    return collisions;
+}
+
+pub fn run(map: &Vec<Vec<bool>>, slope: Slope) -> Result<Run, Error> {
+    check_map(map)?;
+    if slope.dy == 0 {
+        return Err(Error::InvalidSlope(slope));
+    }
+
+    return Ok(Run { slope: slope, collisions: collisions(map, slope) });
+}
+
+pub fn run_all(map: &Vec<Vec<bool>>, slopes: &[Slope]) -> Result<Vec<Run>, Error> {
+    return slopes.iter().map(|slope| run(map, *slope)).collect();
+}
+
+// The most slopes `every_slope` will list.
+pub const MAX_SLOPES: usize = 1_000_000;
+
+// Every slope going at most `max_dx` columns either way and from 1 to `max_dy` rows down each step.
+pub fn every_slope(max_dx: usize, max_dy: usize) -> Result<Vec<Slope>, Error> {
+    let count = max_dx.checked_mul(2).and_then(|dx| dx.checked_add(1)).and_then(|dxs| dxs.checked_mul(max_dy));
+    if count.is_none_or(|count| count > MAX_SLOPES) {
+        return Err(Error::TooManySlopes { max_dx: max_dx, max_dy: max_dy });
+    }
+
+    // Checking the count keeps max_dx far below the largest i64.
+    let max_dx = max_dx as i64;
+    let mut slopes = Vec::new();
+    for dy in 1..=max_dy {
+        for dx in -max_dx..=max_dx {
+            slopes.push(Slope { dx: dx, dy: dy });
+        }
+    }
+
+    return Ok(slopes);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Goal {
+    FewestTrees,
+    MostTrees,
+}
+
+// The run that best meets `goal`, the first of them on a tie.
+pub fn best_run(runs: &Vec<Run>, goal: Goal) -> Option<&Run> {
+    let mut best: Option<&Run> = None;
+    for run in runs {
+        let better = match best {
+            None => true,
+            Some(best) if goal == Goal::FewestTrees => run.collisions.len() < best.collisions.len(),
+            Some(best) => run.collisions.len() > best.collisions.len(),
+        };
+        if better {
+            best = Some(run);
+        }
+    }
+
+    return best;
+}
+
+fn product(runs: &Vec<Run>) -> Result<usize, Error> {
+    return runs.iter().try_fold(1usize, |product, run| product.checked_mul(run.collisions.len())).ok_or(Error::TooManyTrees);
+}
+
+// The trees hit on each slope, multiplied together.
+pub fn product_of_trees(map: &Vec<Vec<bool>>, slopes: &[Slope]) -> Result<usize, Error> {
+    return product(&run_all(map, slopes)?);
+}
+
+pub fn trees_json(runs: &[&Run]) -> Value {
+    return runs.iter().map(|run| json!({ "slope": run.slope.to_string(), "trees": run.collisions.len() })).collect();
+}
+
+// The product along with how many trees were hit on each slope.
+pub fn product_of_trees_with_details(map: &Vec<Vec<bool>>, slopes: &[Slope]) -> Result<(usize, Value), Error> {
+    let runs = run_all(map, slopes)?;
+    return Ok((product(&runs)?, json!({ "slopes": trees_json(&runs.iter().collect::<Vec<&Run>>()) })));
+}
+
+pub fn solve(map: &Vec<Vec<bool>>) -> Result<usize, Error> {
+    return product_of_trees(map, &[SLOPE]);
+}
+
+pub fn part1(input: &Input) -> Result<usize, Error> {
+    let map = parse_file(input)?;
+    return solve(&map);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let map = parse_file(input)?;
+    return product_of_trees_with_details(&map, &[SLOPE]);
+}
@@ -0,0 +1,101 @@
+use std::path::Path;
+
+use crate::{run, Error, Slope};
+
+// Each square of the map is drawn as a block this many pixels wide in the images.
+pub const PIXELS_PER_SQUARE: usize = 4;
+
+// The widest rendering, in squares, before the path is too far across to draw.
+pub const MAX_COLUMNS: i64 = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageFormat {
+    Ppm,
+    Pgm,
+}
+
+// The format is picked by the file's extension.
+pub fn image_format(file_name: &Path) -> Option<ImageFormat> {
+    return match file_name.extension()?.to_str()?.to_lowercase().as_str() {
+        "ppm" => Some(ImageFormat::Ppm),
+        "pgm" => Some(ImageFormat::Pgm),
+        _ => None,
+    };
+}
+
+// Draws the map the way the puzzle does, with the path marked 'O' on open squares and 'X' on the trees it hits.
+// Like the puzzle the starting square is only marked when it's a tree, as it's still counted then. The map is repeated
+// as many times as the path needs, to the left as well as the right.
+pub fn render(map: &Vec<Vec<bool>>, slope: Slope) -> Result<Vec<String>, Error> {
+    run(map, slope)?;
+
+    let width = map[0].len() as i64;
+    let steps = map.len().div_ceil(slope.dy);
+    let last_x = match (steps as i64 - 1).checked_mul(slope.dx) {
+        Some(last_x) if last_x.abs() <= MAX_COLUMNS => last_x,
+        _ => return Err(Error::TooWide(slope)),
+    };
+
+    // Whole copies of the map, from the one the path ends in on the left to the one it ends in on the right.
+    let first_column = last_x.min(0).div_euclid(width) * width;
+    let end_column = (last_x.max(0).div_euclid(width) + 1) * width;
+
+    let mut rows = Vec::new();
+    for (y, squares) in map.iter().enumerate() {
+        let path_x = if y % slope.dy == 0 { Some((y / slope.dy) as i64 * slope.dx) } else { None };
+        let row: String = (first_column..end_column)
+            .map(|x| {
+                let tree = squares[x.rem_euclid(width) as usize];
+                match (Some(x) == path_x, tree) {
+                    (true, true) => 'X',
+                    (true, false) if y > 0 => 'O',
+                    (_, true) => '#',
+                    (_, false) => '.',
+                }
+            })
+            .collect();
+        rows.push(row);
+    }
+
+    return Ok(rows);
+}
+
+// In colour trees are green, the path is blue and the trees it hits are red. In grey the path is darker than the open
+// squares around it but lighter than the trees, and the trees it hits are black.
+fn pixel(square: char, format: ImageFormat) -> &'static [u8] {
+    return match (format, square) {
+        (ImageFormat::Ppm, 'X') => &[220, 20, 20],
+        (ImageFormat::Ppm, 'O') => &[70, 130, 230],
+        (ImageFormat::Ppm, '#') => &[20, 100, 40],
+        (ImageFormat::Ppm, _) => &[245, 245, 245],
+        (ImageFormat::Pgm, 'X') => &[0],
+        (ImageFormat::Pgm, 'O') => &[170],
+        (ImageFormat::Pgm, '#') => &[80],
+        (ImageFormat::Pgm, _) => &[255],
+    };
+}
+
+// Encodes a rendering as a binary PPM (colour) or PGM (grey) image.
+pub fn encode(rows: &Vec<String>, format: ImageFormat) -> Vec<u8> {
+    let width = rows.first().map_or(0, |row| row.chars().count()) * PIXELS_PER_SQUARE;
+    let height = rows.len() * PIXELS_PER_SQUARE;
+    let magic = match format {
+        ImageFormat::Ppm => "P6",
+        ImageFormat::Pgm => "P5",
+    };
+
+    let mut image = format!("{}\n{} {}\n255\n", magic, width, height).into_bytes();
+    for row in rows {
+        let mut line = Vec::new();
+        for square in row.chars() {
+            for _ in 0..PIXELS_PER_SQUARE {
+                line.extend_from_slice(pixel(square, format));
+            }
+        }
+        for _ in 0..PIXELS_PER_SQUARE {
+            image.extend_from_slice(&line);
+        }
+    }
+
+    return image;
+}
@@ -0,0 +1,25 @@
+use aoc_common::{Input, Value};
+
+pub use day3a::{command, best_run, every_slope, parse_file, parse_slope, product_of_trees, product_of_trees_with_details, run, run_all, Error, Goal, Run, Slope};
+
+pub const SLOPES: [Slope; 5] = [
+    Slope { dx: 1, dy: 1 },
+    Slope { dx: 3, dy: 1 },
+    Slope { dx: 5, dy: 1 },
+    Slope { dx: 7, dy: 1 },
+    Slope { dx: 1, dy: 2 },
+];
+
+pub fn solve(map: &Vec<Vec<bool>>) -> Result<usize, Error> {
+    return product_of_trees(map, &SLOPES);
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let map = parse_file(input)?;
+    return solve(&map);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let map = parse_file(input)?;
+    return product_of_trees_with_details(&map, &SLOPES);
+}
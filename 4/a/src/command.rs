@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+use aoc_common::cli::DayOption;
+use aoc_common::Input;
+
+use crate::convert::{read, write, Format};
+use crate::count_valid_with_details;
+use crate::report::{report_all, Report};
+use crate::schema::{load_schema, Schema};
+
+// How wide the lines are when converting to the batch format, unless --width is given.
+const DEFAULT_WIDTH: usize = 80;
+
+pub const OPTIONS: &[DayOption] = &[
+    DayOption { name: "schema", value: Some("<file>"), description: "Check the documents against the fields in this schema file instead" },
+    DayOption { name: "report", value: Some("<text|json>"), description: "Print what's wrong with each passport instead" },
+    DayOption { name: "invalid", value: None, description: "Only report the invalid passports" },
+    DayOption { name: "from", value: Some("<batch|csv|json>"), description: "Read the passports in this format (batch by default)" },
+    DayOption { name: "convert", value: Some("<batch|csv|json>"), description: "Print the passports in this format instead" },
+    DayOption { name: "width", value: Some("<characters>"), description: "Wrap the lines of --convert batch at this width (80 by default)" },
+];
+
+fn parse_format(name: Option<&str>) -> Option<Format> {
+    return name.map(|name| match Format::from_name(name) {
+        None => aoc_common::exit_with_error(format!("Unknown format '{}', expected batch, csv or json", name)),
+        Some(format) => format,
+    });
+}
+
+fn print_reports(reports: &Vec<Report>, format: &str, invalid_only: bool) {
+    let reports = reports.iter().filter(|report| !invalid_only || !report.is_valid());
+    if format == "json" {
+        println!("{}", aoc_common::Value::Array(reports.map(|report| report.to_json()).collect()));
+    } else {
+        for report in reports {
+            println!("{}", report);
+        }
+    }
+}
+
+pub fn main(part: usize, default_schema: &Schema, default_description: &str) {
+    let options = aoc_common::cli::parse_args_with(OPTIONS);
+    let report_format = options.value("report");
+    if let Some(format) = report_format {
+        if format != "text" && format != "json" {
+            aoc_common::exit_with_error(format!("Unknown report format '{}', expected text or json", format));
+        }
+    }
+
+    let from = parse_format(options.value("from")).unwrap_or(Format::Batch);
+    let convert = parse_format(options.value("convert"));
+    let width = match options.value("width").map(|width| width.parse::<usize>()) {
+        None => DEFAULT_WIDTH,
+        Some(Ok(width)) if width > 0 => width,
+        Some(_) => aoc_common::exit_with_error("--width expects a number of characters above 0"),
+    };
+
+    let start = Instant::now();
+    let schema = options.value("schema").map(|file_name| match load_schema(&Input::File(PathBuf::from(file_name))) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(schema) => schema
+    });
+    let description = match options.value("schema") {
+        Some(file_name) => format!("Documents valid under the schema in {}", file_name),
+        None => String::from(default_description),
+    };
+
+    let passports = match read(&options.input, from) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(passports) => passports
+    };
+    if let Some(format) = convert {
+        print!("{}", write(&passports, format, width));
+        return;
+    }
+
+    let schema = schema.as_ref().unwrap_or(default_schema);
+    if let Some(format) = report_format {
+        print_reports(&report_all(schema, &passports), format, options.flag("invalid"));
+        return;
+    }
+
+    let (valid_count, mut details) = count_valid_with_details(&passports, schema);
+
+    if options.json {
+        details["input"] = aoc_common::json!(options.input.to_string());
+        details["description"] = aoc_common::json!(description);
+        aoc_common::output::print_json(4, part, &valid_count, start.elapsed(), details);
+    } else {
+        print!("Valid Passports: {}", valid_count);
+    }
+}
@@ -0,0 +1,208 @@
+use aoc_common::output::csv_row;
+use aoc_common::{Input, Value};
+
+use crate::{parse_file, Error, Field, Passport};
+
+const CSV_HEADER: &str = "record,key,value";
+const FIELD_FORM: &str = "a key without whitespace or ':' and a value without whitespace";
+
+// The formats a batch of passports can be read from and written to. Every format keeps the fields of each passport
+// in order, including duplicates and keys no schema knows about, so converting between them loses nothing.
+//
+//     batch    the puzzle's format: key:value pairs separated by whitespace, with a blank line after each passport
+//     csv      a "record,key,value" header, then a row per field with the number of the passport it's in
+//     json     a line per passport, each an array of [key, value] pairs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Batch,
+    Csv,
+    JsonLines,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Format> {
+        return match name {
+            "batch" => Some(Format::Batch),
+            "csv" => Some(Format::Csv),
+            "json" => Some(Format::JsonLines),
+            _ => None,
+        };
+    }
+}
+
+// Whether the field can be written back to a batch file, so it can be read again in any format.
+fn is_batch_field(key: &str, value: &str) -> bool {
+    return !key.is_empty() && !key.contains(':') && !key.contains(char::is_whitespace) && !value.contains(char::is_whitespace);
+}
+
+// Splits a row written by `csv_row` back into its fields.
+fn parse_csv_row(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut characters = line.chars().peekable();
+    loop {
+        let mut field = String::new();
+        if characters.peek() == Some(&'"') {
+            characters.next();
+            loop {
+                match characters.next()? {
+                    '"' if characters.peek() == Some(&'"') => {
+                        characters.next();
+                        field.push('"');
+                    },
+                    '"' => break,
+                    character => field.push(character),
+                }
+            }
+        } else {
+            while let Some(character) = characters.next_if(|character| *character != ',') {
+                field.push(character);
+            }
+        }
+        fields.push(field);
+
+        match characters.next() {
+            None => return Some(fields),
+            Some(',') => (),
+            Some(_) => return None,
+        }
+    }
+}
+
+// Adds a field to the passport it belongs to, starting a new passport when `record` changes.
+fn push_field(passports: &mut Vec<Passport>, records: &mut Vec<String>, record: &str, field: Field) {
+    if records.last().map(|last| last.as_str()) != Some(record) {
+        records.push(String::from(record));
+        passports.push(Passport { record: passports.len() + 1, line: field.line, last_line: field.line, fields: Vec::new() });
+    }
+
+    let passport = passports.last_mut().unwrap();
+    passport.last_line = field.line;
+    passport.fields.push(field);
+}
+
+fn parse_csv(input: &Input) -> Result<Vec<Passport>, Error> {
+    let lines = aoc_common::read_lines(input)?;
+    let malformed = |line_number: usize, line: &str, expected: &'static str| Error::Malformed { line: line_number, text: String::from(line), expected: expected };
+
+    let mut passports = Vec::new();
+    let mut records = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        let line_number = index + 1;
+        if index == 0 {
+            if line.trim() != CSV_HEADER {
+                return Err(malformed(line_number, line, "the header \"record,key,value\""));
+            }
+            continue;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (record, key, value) = match parse_csv_row(line).as_deref() {
+            Some([record, key, value]) => (record.clone(), key.clone(), value.clone()),
+            _ => return Err(malformed(line_number, line, "\"<record>,<key>,<value>\"")),
+        };
+        if !is_batch_field(&key, &value) {
+            return Err(malformed(line_number, line, FIELD_FORM));
+        }
+
+        push_field(&mut passports, &mut records, &record, Field { key: key, value: value, line: line_number });
+    }
+
+    return Ok(passports);
+}
+
+fn parse_json_lines(input: &Input) -> Result<Vec<Passport>, Error> {
+    let lines = aoc_common::read_lines(input)?;
+    const EXPECTED: &str = "an array of [\"<key>\", \"<value>\"] pairs, with at least one pair";
+
+    let mut passports = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        let line_number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let malformed = |expected: &'static str| Error::Malformed { line: line_number, text: String::from(line), expected: expected };
+        let pairs = match serde_json::from_str::<Value>(line) {
+            Ok(Value::Array(pairs)) if !pairs.is_empty() => pairs,
+            _ => return Err(malformed(EXPECTED)),
+        };
+
+        let mut fields = Vec::new();
+        for pair in &pairs {
+            let (key, value) = match pair.as_array().map(|pair| pair.as_slice()) {
+                Some([Value::String(key), Value::String(value)]) => (key, value),
+                _ => return Err(malformed(EXPECTED)),
+            };
+            if !is_batch_field(key, value) {
+                return Err(malformed(FIELD_FORM));
+            }
+            fields.push(Field { key: key.clone(), value: value.clone(), line: line_number });
+        }
+
+        passports.push(Passport { record: passports.len() + 1, line: line_number, last_line: line_number, fields: fields });
+    }
+
+    return Ok(passports);
+}
+
+pub fn read(input: &Input, format: Format) -> Result<Vec<Passport>, Error> {
+    return match format {
+        Format::Batch => parse_file(input),
+        Format::Csv => parse_csv(input),
+        Format::JsonLines => parse_json_lines(input),
+    };
+}
+
+// Writes each passport's fields as key:value pairs, starting a new line before a pair that would take it past `width`
+// characters. A pair that's longer than that on its own gets a line to itself.
+pub fn to_batch(passports: &Vec<Passport>, width: usize) -> String {
+    let mut records = Vec::new();
+    for passport in passports {
+        let mut lines: Vec<String> = Vec::new();
+        for field in &passport.fields {
+            let pair = format!("{}:{}", field.key, field.value);
+            match lines.last_mut() {
+                Some(line) if line.chars().count() + 1 + pair.chars().count() <= width => {
+                    line.push(' ');
+                    line.push_str(&pair);
+                },
+                _ => lines.push(pair),
+            }
+        }
+        records.push(lines.join("\n") + "\n");
+    }
+
+    return records.join("\n");
+}
+
+pub fn to_csv(passports: &Vec<Passport>) -> String {
+    let mut rows = vec![String::from(CSV_HEADER)];
+    for passport in passports {
+        for field in &passport.fields {
+            rows.push(csv_row(&[passport.record.to_string(), field.key.clone(), field.value.clone()]));
+        }
+    }
+
+    return rows.join("\n") + "\n";
+}
+
+pub fn to_json_lines(passports: &Vec<Passport>) -> String {
+    let mut lines = String::new();
+    for passport in passports {
+        let pairs: Vec<Value> = passport.fields.iter().map(|field| aoc_common::json!([field.key, field.value])).collect();
+        lines.push_str(&Value::Array(pairs).to_string());
+        lines.push('\n');
+    }
+
+    return lines;
+}
+
+pub fn write(passports: &Vec<Passport>, format: Format, width: usize) -> String {
+    return match format {
+        Format::Batch => to_batch(passports, width),
+        Format::Csv => to_csv(passports),
+        Format::JsonLines => to_json_lines(passports),
+    };
+}
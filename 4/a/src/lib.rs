@@ -0,0 +1,131 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Record, Value};
+use lazy_static::lazy_static;
+use log::info;
+
+pub mod command;
+pub mod convert;
+pub mod report;
+pub mod schema;
+
+use schema::Schema;
+
+lazy_static! {
+    // Part 1 only checks that the fields are there.
+    pub static ref PRESENCE_SCHEMA: Schema = schema::parse_schema("the presence schema", include_str!("../presence.schema")).unwrap();
+}
+
+// One key:value pair of a passport, and the (1 based) line it's on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub key: String,
+    pub value: String,
+    pub line: usize,
+}
+
+// The fields of a passport in the order they were written. `record` is which passport of the batch it is, counting
+// from 1, and it's written on the lines from `line` to `last_line`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Passport {
+    pub record: usize,
+    pub line: usize,
+    pub last_line: usize,
+    pub fields: Vec<Field>,
+}
+
+impl Passport {
+    // The value given for `key`, the last one if it's given more than once.
+    pub fn value(&self, key: &str) -> Option<&str> {
+        return self.fields.iter().rev().find(|field| field.key == key).map(|field| field.value.as_str());
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_passport(index: usize, record: &Record) -> Result<Passport, Error> {
+    let mut fields = Vec::new();
+    for (offset, line) in record.lines.iter().enumerate() {
+        let line_number = record.line + offset;
+        for field in line.split_whitespace() {
+            let (key, value) = match field.split_once(':') {
+                None => return Err(Error::Malformed { line: line_number, text: String::from(line), expected: "space separated <field>:<value> pairs" }),
+                Some(pair) => pair
+            };
+
+            fields.push(Field { key: String::from(key), value: String::from(value), line: line_number });
+        }
+    }
+
+    return Ok(Passport {
+        record: index + 1,
+        line: record.line,
+        last_line: record.line + record.lines.len() - 1,
+        fields: fields,
+    });
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Passport>, Error> {
+    let records = aoc_common::read_records(input)?;
+    return records.iter().enumerate().map(|(index, record)| parse_passport(index, record)).collect();
+}
+
+pub fn count_valid(passports: &Vec<Passport>, schema: &Schema) -> usize {
+    return count_valid_with_details(passports, schema).0;
+}
+
+// The count along with how many passports there were and which of them are invalid, by record number.
+pub fn count_valid_with_details(passports: &Vec<Passport>, schema: &Schema) -> (usize, Value) {
+    let mut invalid = Vec::new();
+    for report in report::report_all(schema, passports) {
+        if !report.is_valid() {
+            info!("{}", report);
+            invalid.push(report.record);
+        }
+    }
+
+    return (passports.len() - invalid.len(), json!({ "passports": passports.len(), "invalid_records": invalid }));
+}
+
+pub fn solve(passports: &Vec<Passport>) -> Result<usize, Error> {
+    return Ok(count_valid(passports, &PRESENCE_SCHEMA));
+}
+
+pub fn part1(input: &Input) -> Result<usize, Error> {
+    let passports = parse_file(input)?;
+    return solve(&passports);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let passports = parse_file(input)?;
+    return Ok(count_valid_with_details(&passports, &PRESENCE_SCHEMA));
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+
+use aoc_common::{json, Value};
+
+use crate::schema::Schema;
+use crate::{Field, Passport};
+
+// A field whose value isn't of the type the schema gives it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidField {
+    pub field: Field,
+    pub reason: String,
+}
+
+// Everything wrong with one passport. It's valid when nothing is missing or invalid, and the unknown and duplicated
+// fields are only warnings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    // Which passport of the batch this is, counting from 1, and the lines it's written on.
+    pub record: usize,
+    pub first_line: usize,
+    pub last_line: usize,
+    // Required fields that aren't there.
+    pub missing: Vec<String>,
+    pub invalid: Vec<InvalidField>,
+    // Fields the schema doesn't have.
+    pub unknown: Vec<Field>,
+    // Every field after the first with the same key.
+    pub duplicates: Vec<Field>,
+}
+
+pub fn report(schema: &Schema, passport: &Passport) -> Report {
+    let mut report = Report {
+        record: passport.record,
+        first_line: passport.line,
+        last_line: passport.last_line,
+        missing: Vec::new(),
+        invalid: Vec::new(),
+        unknown: Vec::new(),
+        duplicates: Vec::new(),
+    };
+
+    for rule in &schema.fields {
+        if rule.required && passport.value(&rule.key).is_none() {
+            report.missing.push(rule.key.clone());
+        }
+    }
+
+    for (index, field) in passport.fields.iter().enumerate() {
+        if passport.fields[..index].iter().any(|earlier| earlier.key == field.key) {
+            report.duplicates.push(field.clone());
+        }
+
+        match schema.rule(&field.key) {
+            None => report.unknown.push(field.clone()),
+            Some(rule) => if let Some(reason) = rule.field_type.violation(&field.value) {
+                report.invalid.push(InvalidField { field: field.clone(), reason: reason });
+            },
+        }
+    }
+
+    return report;
+}
+
+pub fn report_all(schema: &Schema, passports: &Vec<Passport>) -> Vec<Report> {
+    return passports.iter().map(|passport| report(schema, passport)).collect();
+}
+
+fn field_json(field: &Field) -> Value {
+    return json!({ "key": field.key, "value": field.value, "line": field.line });
+}
+
+impl Report {
+    pub fn is_valid(&self) -> bool {
+        return self.missing.is_empty() && self.invalid.is_empty();
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        return !self.unknown.is_empty() || !self.duplicates.is_empty();
+    }
+
+    pub fn to_json(&self) -> Value {
+        let invalid: Vec<Value> = self.invalid.iter().map(|invalid| {
+            let mut field = field_json(&invalid.field);
+            field["reason"] = json!(invalid.reason);
+            field
+        }).collect();
+
+        return json!({
+            "record": self.record,
+            "first_line": self.first_line,
+            "last_line": self.last_line,
+            "valid": self.is_valid(),
+            "missing": self.missing,
+            "invalid": invalid,
+            "warnings": {
+                "unknown": self.unknown.iter().map(field_json).collect::<Vec<Value>>(),
+                "duplicates": self.duplicates.iter().map(field_json).collect::<Vec<Value>>(),
+            },
+        });
+    }
+}
+
+// One line per passport, listing what's wrong with it and then any warnings.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Passport {} (lines {}-{}): ", self.record, self.first_line, self.last_line)?;
+
+        let mut problems = Vec::new();
+        if !self.missing.is_empty() {
+            problems.push(format!("missing {}", self.missing.join(", ")));
+        }
+        for invalid in &self.invalid {
+            problems.push(format!("invalid {} \"{}\" on line {} ({})", invalid.field.key, invalid.field.value, invalid.field.line, invalid.reason));
+        }
+        if problems.is_empty() {
+            problems.push(String::from("valid"));
+        }
+
+        let mut warnings = Vec::new();
+        for field in &self.unknown {
+            warnings.push(format!("unknown {} \"{}\" on line {}", field.key, field.value, field.line));
+        }
+        for field in &self.duplicates {
+            warnings.push(format!("duplicate {} \"{}\" on line {}", field.key, field.value, field.line));
+        }
+        if !warnings.is_empty() {
+            problems.push(format!("warnings: {}", warnings.join(", ")));
+        }
+
+        return write!(f, "{}", problems.join("; "));
+    }
+}
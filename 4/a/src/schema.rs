@@ -0,0 +1,188 @@
+use std::fmt;
+
+use aoc_common::{Input, InputError};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::Error;
+
+lazy_static! {
+    static ref INT_RANGE_REGEX: Regex = Regex::new(r"^(?P<min>-?\d+)-(?P<max>-?\d+)$").unwrap();
+    static ref UNIT_RANGE_REGEX: Regex = Regex::new(r"^(?P<min>-?\d+)-(?P<max>-?\d+)(?P<unit>[^\d\s-]+)$").unwrap();
+}
+
+// What a field's value has to look like.
+#[derive(Debug)]
+pub enum FieldType {
+    Any,
+    IntRange { min: i64, max: i64 },
+    // A number followed by one of the units, each with its own range.
+    UnitRange(Vec<(String, i64, i64)>),
+    // The pattern as it was written, and the regex that matches it against the whole value.
+    Regex { pattern: String, regex: Regex },
+    Enum(Vec<String>),
+}
+
+impl FieldType {
+    // Why `value` isn't of this type, or None if it is.
+    pub fn violation(&self, value: &str) -> Option<String> {
+        return match self {
+            FieldType::Any => None,
+            FieldType::IntRange { min, max } => match value.parse::<i64>() {
+                Err(_) => Some(String::from("isn't a number")),
+                Ok(number) if number < *min || number > *max => Some(format!("{} isn't between {} and {}", number, min, max)),
+                Ok(_) => None,
+            },
+            FieldType::UnitRange(units) => {
+                let unit = units.iter().find_map(|(unit, min, max)| Some((unit, min, max, value.strip_suffix(unit.as_str())?)));
+                match unit {
+                    None => Some(format!("doesn't end in {}", format_choices(units.iter().map(|(unit, _, _)| unit)))),
+                    Some((unit, min, max, number)) => match number.parse::<i64>() {
+                        Err(_) => Some(format!("isn't a number of {}", unit)),
+                        Ok(number) if number < *min || number > *max => Some(format!("{}{} isn't between {}{} and {}{}", number, unit, min, unit, max, unit)),
+                        Ok(_) => None,
+                    },
+                }
+            },
+            FieldType::Regex { pattern, regex } => if regex.is_match(value) { None } else { Some(format!("doesn't match {}", pattern)) },
+            FieldType::Enum(choices) => if choices.iter().any(|choice| choice == value) { None } else { Some(format!("isn't {}", format_choices(choices.iter()))) },
+        };
+    }
+}
+
+fn format_choices<'a>(choices: impl Iterator<Item = &'a String>) -> String {
+    let choices: Vec<&str> = choices.map(|choice| choice.as_str()).collect();
+    return match choices.split_last() {
+        None => String::new(),
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("one of {} or {}", rest.join(", "), last),
+    };
+}
+
+// Displaying a type gives it back in the syntax of the schema file.
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            FieldType::Any => Ok(()),
+            FieldType::IntRange { min, max } => write!(f, "int {}-{}", min, max),
+            FieldType::UnitRange(units) => {
+                let ranges: Vec<String> = units.iter().map(|(unit, min, max)| format!("{}-{}{}", min, max, unit)).collect();
+                write!(f, "unit {}", ranges.join(" "))
+            },
+            FieldType::Regex { pattern, .. } => write!(f, "regex {}", pattern),
+            FieldType::Enum(choices) => write!(f, "enum {}", choices.join(" ")),
+        };
+    }
+}
+
+#[derive(Debug)]
+pub struct FieldRule {
+    pub key: String,
+    pub required: bool,
+    pub field_type: FieldType,
+}
+
+// The fields a document can have, in the order the schema lists them.
+#[derive(Debug)]
+pub struct Schema {
+    pub fields: Vec<FieldRule>,
+}
+
+impl Schema {
+    pub fn rule(&self, key: &str) -> Option<&FieldRule> {
+        return self.fields.iter().find(|rule| rule.key == key);
+    }
+
+    pub fn keys(&self) -> Vec<String> {
+        return self.fields.iter().map(|rule| rule.key.clone()).collect();
+    }
+}
+
+fn parse_range(regex: &Regex, text: &str) -> Option<(i64, i64, String)> {
+    let captures = regex.captures(text)?;
+    let min = captures["min"].parse().ok()?;
+    let max = captures["max"].parse().ok()?;
+    let unit = captures.name("unit").map_or(String::new(), |unit| String::from(unit.as_str()));
+    return if min <= max { Some((min, max, unit)) } else { None };
+}
+
+fn parse_type(text: &str) -> Result<FieldType, &'static str> {
+    let (keyword, rest) = text.split_once(' ').unwrap_or((text, ""));
+    let rest = rest.trim();
+    return match keyword {
+        "" => Ok(FieldType::Any),
+        "int" => match parse_range(&INT_RANGE_REGEX, rest) {
+            None => Err("\"int <min>-<max>\""),
+            Some((min, max, _)) => Ok(FieldType::IntRange { min: min, max: max }),
+        },
+        "unit" => {
+            let units: Option<Vec<(String, i64, i64)>> = rest.split_whitespace()
+                .map(|range| parse_range(&UNIT_RANGE_REGEX, range).map(|(min, max, unit)| (unit, min, max)))
+                .collect();
+            match units {
+                Some(units) if !units.is_empty() => Ok(FieldType::UnitRange(units)),
+                _ => Err("\"unit <min>-<max><unit> ...\""),
+            }
+        },
+        "regex" => match Regex::new(&format!("^(?:{})$", rest)) {
+            Ok(regex) if !rest.is_empty() => Ok(FieldType::Regex { pattern: String::from(rest), regex: regex }),
+            _ => Err("a valid regular expression after \"regex\""),
+        },
+        "enum" if !rest.is_empty() => Ok(FieldType::Enum(rest.split_whitespace().map(String::from).collect())),
+        "enum" => Err("the allowed values after \"enum\""),
+        _ => Err("a type of int, unit, regex or enum"),
+    };
+}
+
+// Reads a schema, one field per line. Blank lines and lines starting with '#' are skipped.
+//
+//     <key> required|optional [<type>]
+//
+//     cid optional                             anything, or nothing at all
+//     byr required int 1920-2002               a whole number from 1920 to 2002
+//     hgt required unit 150-193cm 59-76in      a whole number of cm from 150 to 193, or of in from 59 to 76
+//     hcl required regex ^#[0-9a-f]{6}$        the whole value matches the regular expression
+//     ecl required enum amb blu brn            one of the listed values
+pub fn load_schema(input: &Input) -> Result<Schema, Error> {
+    let rules = aoc_common::parse_lines_with(input, |line_number, line| {
+        let parse_error = |expected: &str| InputError::Parse { file_name: input.to_string(), line: line_number, text: String::from(line), reason: format!("expected {}", expected) };
+
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+
+        let mut words = line.splitn(3, char::is_whitespace);
+        let key = words.next().unwrap_or("");
+        let required = match words.next() {
+            Some("required") => true,
+            Some("optional") => false,
+            _ => return Err(parse_error("\"<key> required\" or \"<key> optional\", then the type")),
+        };
+        let field_type = match parse_type(words.next().unwrap_or("").trim()) {
+            Err(expected) => return Err(parse_error(expected)),
+            Ok(field_type) => field_type
+        };
+
+        return Ok(Some((line_number, String::from(line), FieldRule { key: String::from(key), required: required, field_type: field_type })));
+    })?;
+
+    let mut schema = Schema { fields: Vec::new() };
+    for (line_number, line, rule) in rules.into_iter().flatten() {
+        if schema.rule(&rule.key).is_some() {
+            return Err(Error::Input(InputError::Parse {
+                file_name: input.to_string(),
+                line: line_number,
+                text: line,
+                reason: format!("{} is already in the schema", rule.key),
+            }));
+        }
+        schema.fields.push(rule);
+    }
+
+    return Ok(schema);
+}
+
+pub fn parse_schema(name: &str, text: &str) -> Result<Schema, Error> {
+    return load_schema(&Input::Text { name: String::from(name), text: String::from(text) });
+}
@@ -0,0 +1,24 @@
+use aoc_common::{Input, Value};
+use lazy_static::lazy_static;
+
+pub use day4a::schema::{load_schema, parse_schema, FieldRule, FieldType, Schema};
+pub use day4a::report::{report, report_all, InvalidField, Report};
+pub use day4a::{command, count_valid, count_valid_with_details, parse_file, Error, Field, Passport};
+
+lazy_static! {
+    pub static ref PASSPORT_SCHEMA: Schema = parse_schema("the passport schema", include_str!("../passport.schema")).unwrap();
+}
+
+pub fn solve(passports: &Vec<Passport>) -> Result<usize, Error> {
+    return Ok(count_valid(passports, &PASSPORT_SCHEMA));
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let passports = parse_file(input)?;
+    return solve(&passports);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let passports = parse_file(input)?;
+    return Ok(count_valid_with_details(&passports, &PASSPORT_SCHEMA));
+}
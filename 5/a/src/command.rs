@@ -0,0 +1,62 @@
+use aoc_common::cli::{DayOption, Options};
+
+use crate::seat_code::{Geometry, SeatCode, PLANE};
+use crate::seat_map::seat_map;
+use crate::{parse_file_with, Error};
+
+pub const OPTIONS: &[DayOption] = &[
+    DayOption { name: "rows", value: Some("<count>"), description: "The plane has this many rows of seats (128 by default)" },
+    DayOption { name: "columns", value: Some("<count>"), description: "Each row has this many seats (8 by default)" },
+    DayOption { name: "encode", value: None, description: "Read seat IDs, one per line, and print their boarding passes instead" },
+    DayOption { name: "seat-map", value: Some("<text|json>"), description: "Print the plane's seats, its empty seats and duplicate boarding passes instead" },
+];
+
+fn parse_count(options: &Options, name: &str, default: usize) -> usize {
+    return match options.value(name).map(|count| count.parse::<usize>()) {
+        None => default,
+        Some(Ok(count)) if count > 0 => count,
+        Some(_) => aoc_common::exit_with_error(format!("--{} expects a number above 0", name)),
+    };
+}
+
+pub fn seat_code(options: &Options) -> SeatCode {
+    return SeatCode::new(Geometry { rows: parse_count(options, "rows", PLANE.rows), columns: parse_count(options, "columns", PLANE.columns) });
+}
+
+// Prints the plane's seat map for `--seat-map`.
+pub fn print_seat_map(options: &Options, seat_code: &SeatCode, format: &str) {
+    if format != "text" && format != "json" {
+        aoc_common::exit_with_error(format!("Unknown seat map format '{}', expected text or json", format));
+    }
+
+    let boarding_passes = match parse_file_with(&options.input, seat_code) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(boarding_passes) => boarding_passes
+    };
+    let seat_map = seat_map(&boarding_passes, seat_code);
+    if format == "json" {
+        println!("{}", seat_map.to_json());
+    } else {
+        print!("{}", seat_map.render());
+    }
+}
+
+// Prints the boarding pass for each seat ID in the input.
+pub fn encode(options: &Options, seat_code: &SeatCode) {
+    let boarding_passes = aoc_common::parse_lines_with(&options.input, |line_number, line| match line.trim().parse::<usize>() {
+        Err(_) => Err(Error::Input(aoc_common::InputError::Parse {
+            file_name: options.input.to_string(),
+            line: line_number,
+            text: String::from(line),
+            reason: String::from("expected a seat ID"),
+        })),
+        Ok(id) => seat_code.encode_id(id).map_err(|why| Error::InvalidCode { line: line_number, text: String::from(line), why: why }),
+    });
+
+    match boarding_passes {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(boarding_passes) => for boarding_pass in boarding_passes {
+            println!("{}", boarding_pass);
+        },
+    }
+}
@@ -0,0 +1,98 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+
+pub mod command;
+pub mod seat_code;
+pub mod seat_map;
+
+use seat_code::{CodeError, Seat, SeatCode, PLANE};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardingPass {
+    pub line: usize,
+    pub code: String,
+    pub seat: Seat,
+    pub id: usize,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    InvalidCode { line: usize, text: String, why: CodeError },
+    NoBoardingPasses,
+    NoMissingSeat,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::InvalidCode { line, text, why } => write!(f, "Couldn't parse line {} (\"{}\"): {}", line, text, why),
+            Error::NoBoardingPasses => write!(f, "There are no boarding passes"),
+            Error::NoMissingSeat => write!(f, "Couldn't find a missing boarding pass"),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            Error::InvalidCode { why, .. } => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+pub fn parse_file_with(input: &Input, seat_code: &SeatCode) -> Result<Vec<BoardingPass>, Error> {
+    return aoc_common::parse_lines_with(input, |line_number, line| {
+        let seat = match seat_code.decode(line) {
+            Err(why) => return Err(Error::InvalidCode { line: line_number, text: String::from(line), why: why }),
+            Ok(seat) => seat
+        };
+
+        return Ok(BoardingPass { line: line_number, code: String::from(line), seat: seat, id: seat_code.id(seat) });
+    });
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<BoardingPass>, Error> {
+    return parse_file_with(input, &SeatCode::new(PLANE));
+}
+
+pub fn highest_seat_id(boarding_passes: &Vec<BoardingPass>) -> Result<usize, Error> {
+    return boarding_passes.iter().map(|boarding_pass| boarding_pass.id).max().ok_or(Error::NoBoardingPasses);
+}
+
+// The highest seat ID along with the boarding pass it came from.
+pub fn highest_seat_id_with_details(boarding_passes: &Vec<BoardingPass>) -> Result<(usize, Value), Error> {
+    let highest = boarding_passes.iter().max_by_key(|boarding_pass| boarding_pass.id).ok_or(Error::NoBoardingPasses)?;
+    return Ok((highest.id, json!({
+        "boarding_passes": boarding_passes.len(),
+        "line": highest.line,
+        "code": highest.code,
+        "row": highest.seat.row,
+        "column": highest.seat.column
+    })));
+}
+
+pub fn solve(boarding_passes: &Vec<BoardingPass>) -> Result<usize, Error> {
+    return highest_seat_id(boarding_passes);
+}
+
+pub fn part1(input: &Input) -> Result<usize, Error> {
+    let boarding_passes = parse_file(input)?;
+    return solve(&boarding_passes);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let boarding_passes = parse_file(input)?;
+    return highest_seat_id_with_details(&boarding_passes);
+}
@@ -0,0 +1,145 @@
+use std::error;
+use std::fmt;
+
+// How many rows of seats the plane has, and how many seats are in each row.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometry {
+    pub rows: usize,
+    pub columns: usize,
+}
+
+// The puzzle's plane.
+pub const PLANE: Geometry = Geometry { rows: 128, columns: 8 };
+
+// How many bits it takes to tell `count` things apart.
+fn bits(count: usize) -> usize {
+    return (usize::BITS - count.saturating_sub(1).leading_zeros()) as usize;
+}
+
+impl Geometry {
+    pub fn row_bits(&self) -> usize {
+        return bits(self.rows);
+    }
+
+    pub fn column_bits(&self) -> usize {
+        return bits(self.columns);
+    }
+
+    pub fn seats(&self) -> usize {
+        return self.rows * self.columns;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Seat {
+    pub row: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CodeError {
+    Length { expected: usize, found: usize },
+    Character { position: usize, found: char, expected: &'static str },
+    RowOutOfRange { row: usize, rows: usize },
+    ColumnOutOfRange { column: usize, columns: usize },
+    SeatOutOfRange { id: usize, seats: usize },
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            CodeError::Length { expected, found } => write!(f, "expected {} characters, found {}", expected, found),
+            CodeError::Character { position, found, expected } => write!(f, "expected {} at position {}, found '{}'", expected, position, found),
+            CodeError::RowOutOfRange { row, rows } => write!(f, "row {} is past the last of the {} rows", row, rows),
+            CodeError::ColumnOutOfRange { column, columns } => write!(f, "column {} is past the last of the {} columns", column, columns),
+            CodeError::SeatOutOfRange { id, seats } => write!(f, "seat {} is past the last of the {} seats", id, seats),
+        };
+    }
+}
+
+impl error::Error for CodeError {}
+
+// Reads and writes boarding passes for a plane. A pass is the row then the column written in binary, most significant
+// bit first, with F and L for 0 and B and R for 1. A seat's ID counts the seats row by row from the front.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeatCode {
+    pub geometry: Geometry,
+}
+
+impl SeatCode {
+    pub fn new(geometry: Geometry) -> SeatCode {
+        return SeatCode { geometry: geometry };
+    }
+
+    pub fn length(&self) -> usize {
+        return self.geometry.row_bits() + self.geometry.column_bits();
+    }
+
+    pub fn decode(&self, code: &str) -> Result<Seat, CodeError> {
+        let found = code.chars().count();
+        if found != self.length() {
+            return Err(CodeError::Length { expected: self.length(), found: found });
+        }
+
+        let mut row = 0;
+        let mut column = 0;
+        for (index, character) in code.chars().enumerate() {
+            let in_row = index < self.geometry.row_bits();
+            let bit = match (in_row, character) {
+                (true, 'F') | (false, 'L') => 0,
+                (true, 'B') | (false, 'R') => 1,
+                (true, found) => return Err(CodeError::Character { position: index + 1, found: found, expected: "F or B" }),
+                (false, found) => return Err(CodeError::Character { position: index + 1, found: found, expected: "L or R" }),
+            };
+
+            if in_row {
+                row = (row << 1) | bit;
+            } else {
+                column = (column << 1) | bit;
+            }
+        }
+
+        return self.check(Seat { row: row, column: column });
+    }
+
+    pub fn encode(&self, seat: Seat) -> Result<String, CodeError> {
+        self.check(seat)?;
+
+        let row_bits = self.geometry.row_bits();
+        let column_bits = self.geometry.column_bits();
+        let row = (0..row_bits).rev().map(|bit| if (seat.row >> bit) & 1 == 1 { 'B' } else { 'F' });
+        let column = (0..column_bits).rev().map(|bit| if (seat.column >> bit) & 1 == 1 { 'R' } else { 'L' });
+        return Ok(row.chain(column).collect());
+    }
+
+    pub fn id(&self, seat: Seat) -> usize {
+        return seat.row * self.geometry.columns + seat.column;
+    }
+
+    pub fn seat(&self, id: usize) -> Result<Seat, CodeError> {
+        if id >= self.geometry.seats() {
+            return Err(CodeError::SeatOutOfRange { id: id, seats: self.geometry.seats() });
+        }
+
+        return Ok(Seat { row: id / self.geometry.columns, column: id % self.geometry.columns });
+    }
+
+    pub fn decode_id(&self, code: &str) -> Result<usize, CodeError> {
+        return Ok(self.id(self.decode(code)?));
+    }
+
+    pub fn encode_id(&self, id: usize) -> Result<String, CodeError> {
+        return self.encode(self.seat(id)?);
+    }
+
+    fn check(&self, seat: Seat) -> Result<Seat, CodeError> {
+        if seat.row >= self.geometry.rows {
+            return Err(CodeError::RowOutOfRange { row: seat.row, rows: self.geometry.rows });
+        }
+        if seat.column >= self.geometry.columns {
+            return Err(CodeError::ColumnOutOfRange { column: seat.column, columns: self.geometry.columns });
+        }
+
+        return Ok(seat);
+    }
+}
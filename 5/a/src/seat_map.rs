@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use aoc_common::{json, Value};
+
+use crate::seat_code::{Geometry, Seat, SeatCode};
+use crate::BoardingPass;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gap {
+    // Before the first seat with a boarding pass...
+    Front,
+    // ... between two seats with boarding passes...
+    Hole,
+    // ... and after the last one.
+    Back,
+}
+
+impl fmt::Display for Gap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Gap::Front => write!(f, "front"),
+            Gap::Hole => write!(f, "hole"),
+            Gap::Back => write!(f, "back"),
+        };
+    }
+}
+
+// Seats `first` to `last` (IDs, inclusive) have no boarding pass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmptyRange {
+    pub gap: Gap,
+    pub first: usize,
+    pub last: usize,
+}
+
+impl EmptyRange {
+    pub fn seats(&self) -> usize {
+        return self.last - self.first + 1;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatMap {
+    pub seat_code: SeatCode,
+    // How many boarding passes there are for each seat, by ID.
+    pub passes: Vec<usize>,
+    pub empty: Vec<EmptyRange>,
+    // The seats with more than one boarding pass, and the lines the passes are on.
+    pub duplicates: BTreeMap<usize, Vec<usize>>,
+}
+
+// The runs of seats without a boarding pass. A plane with no boarding passes at all is one gap at the front.
+fn empty_ranges(passes: &Vec<usize>) -> Vec<EmptyRange> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for (id, count) in passes.iter().enumerate() {
+        match (start, *count) {
+            (None, 0) => start = Some(id),
+            (Some(first), count) if count > 0 => {
+                let gap = if first == 0 { Gap::Front } else { Gap::Hole };
+                ranges.push(EmptyRange { gap: gap, first: first, last: id - 1 });
+                start = None;
+            },
+            _ => (),
+        }
+    }
+    if let Some(first) = start {
+        let gap = if first == 0 { Gap::Front } else { Gap::Back };
+        ranges.push(EmptyRange { gap: gap, first: first, last: passes.len() - 1 });
+    }
+
+    return ranges;
+}
+
+pub fn seat_map(boarding_passes: &Vec<BoardingPass>, seat_code: &SeatCode) -> SeatMap {
+    let mut passes = vec![0; seat_code.geometry.seats()];
+    let mut lines: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for boarding_pass in boarding_passes {
+        passes[boarding_pass.id] += 1;
+        lines.entry(boarding_pass.id).or_default().push(boarding_pass.line);
+    }
+    lines.retain(|_, lines| lines.len() > 1);
+
+    return SeatMap {
+        seat_code: *seat_code,
+        empty: empty_ranges(&passes),
+        passes: passes,
+        duplicates: lines,
+    };
+}
+
+fn format_seat(seat: Seat) -> String {
+    return format!("row {} column {}", seat.row, seat.column);
+}
+
+impl SeatMap {
+    pub fn geometry(&self) -> Geometry {
+        return self.seat_code.geometry;
+    }
+
+    fn seat(&self, id: usize) -> Seat {
+        let columns = self.geometry().columns;
+        return Seat { row: id / columns, column: id % columns };
+    }
+
+    // How many seats in each row have a boarding pass.
+    pub fn occupancy(&self) -> Vec<usize> {
+        return self.passes.chunks(self.geometry().columns).map(|row| row.iter().filter(|count| **count > 0).count()).collect();
+    }
+
+    // A line per row: '.' for an empty seat, '#' for a seat with a boarding pass and '!' for one with several.
+    pub fn rows(&self) -> Vec<String> {
+        return self.passes.chunks(self.geometry().columns)
+            .map(|row| row.iter().map(|count| match count {
+                0 => '.',
+                1 => '#',
+                _ => '!',
+            }).collect())
+            .collect();
+    }
+
+    pub fn render(&self) -> String {
+        let width = self.geometry().rows.saturating_sub(1).to_string().len();
+        let mut lines = Vec::new();
+        for (row, (seats, taken)) in self.rows().iter().zip(self.occupancy()).enumerate() {
+            lines.push(format!("{:>width$} {} {}", row, seats, taken, width = width));
+        }
+
+        lines.push(String::new());
+        for range in &self.empty {
+            let (first, last) = (format_seat(self.seat(range.first)), format_seat(self.seat(range.last)));
+            let seats = match range.seats() {
+                1 => format!("seat {} ({})", range.first, first),
+                count => format!("{} seats, {} to {} ({} to {})", count, range.first, range.last, first, last),
+            };
+            let gap = match range.gap {
+                Gap::Front => "Empty at the front",
+                Gap::Hole => "Hole",
+                Gap::Back => "Empty at the back",
+            };
+            lines.push(format!("{}: {}", gap, seats));
+        }
+        for (id, duplicate_lines) in &self.duplicates {
+            let duplicate_lines: Vec<String> = duplicate_lines.iter().map(|line| line.to_string()).collect();
+            lines.push(format!("Duplicate boarding passes for seat {} ({}) on lines {}", id, format_seat(self.seat(*id)), duplicate_lines.join(", ")));
+        }
+
+        return lines.join("\n") + "\n";
+    }
+
+    pub fn to_json(&self) -> Value {
+        let empty: Vec<Value> = self.empty.iter().map(|range| json!({
+            "gap": range.gap.to_string(),
+            "first": range.first,
+            "last": range.last,
+            "seats": range.seats(),
+        })).collect();
+        let duplicates: Vec<Value> = self.duplicates.iter().map(|(id, lines)| {
+            let seat = self.seat(*id);
+            json!({ "id": id, "row": seat.row, "column": seat.column, "lines": lines })
+        }).collect();
+
+        return json!({
+            "rows": self.geometry().rows,
+            "columns": self.geometry().columns,
+            "boarding_passes": self.passes.iter().sum::<usize>(),
+            "occupancy": self.occupancy(),
+            "empty": empty,
+            "duplicates": duplicates,
+            "map": self.rows(),
+        });
+    }
+}
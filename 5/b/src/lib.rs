@@ -0,0 +1,40 @@
+use aoc_common::{json, Input, Value};
+
+pub use day5a::seat_code::{CodeError, Geometry, Seat, SeatCode, PLANE};
+pub use day5a::seat_map::{seat_map, EmptyRange, Gap, SeatMap};
+pub use day5a::{command, parse_file, parse_file_with, BoardingPass, Error};
+
+// The empty seat with a boarding pass for the seats either side of it, which is a hole of a single seat.
+pub fn missing_seat(boarding_passes: &Vec<BoardingPass>, geometry: Geometry) -> Result<usize, Error> {
+    let seat_map = seat_map(boarding_passes, &SeatCode::new(geometry));
+    return match seat_map.empty.iter().find(|range| range.gap == Gap::Hole && range.seats() == 1) {
+        None => Err(Error::NoMissingSeat),
+        Some(range) => Ok(range.first),
+    };
+}
+
+// The missing seat along with where it is on the plane and how many boarding passes there were.
+pub fn missing_seat_with_details(boarding_passes: &Vec<BoardingPass>, geometry: Geometry) -> Result<(usize, Value), Error> {
+    let id = missing_seat(boarding_passes, geometry)?;
+    // The seat map only has seats on the plane, so this always finds one.
+    let seat = SeatCode::new(geometry).seat(id).ok();
+    return Ok((id, json!({
+        "boarding_passes": boarding_passes.len(),
+        "row": seat.map(|seat| seat.row),
+        "column": seat.map(|seat| seat.column)
+    })));
+}
+
+pub fn solve(boarding_passes: &Vec<BoardingPass>) -> Result<usize, Error> {
+    return missing_seat(boarding_passes, PLANE);
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let boarding_passes = parse_file(input)?;
+    return solve(&boarding_passes);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let boarding_passes = parse_file(input)?;
+    return missing_seat_with_details(&boarding_passes, PLANE);
+}
@@ -0,0 +1,77 @@
+use std::cmp::Ordering;
+
+use aoc_common::{json, Value};
+
+use crate::{Answers, Group, QUESTIONS};
+
+// How often one question was answered across all the groups.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frequency {
+    pub question: char,
+    pub people: usize,
+    // Groups where anyone answered it, and groups where everyone did.
+    pub anyone: usize,
+    pub everyone: usize,
+}
+
+pub fn frequencies(groups: &Vec<Group>) -> Vec<Frequency> {
+    let mut frequencies: Vec<Frequency> = (0..QUESTIONS)
+        .map(|question| Frequency { question: (b'a' + question as u8) as char, people: 0, anyone: 0, everyone: 0 })
+        .collect();
+
+    for group in groups {
+        let (anyone, everyone) = (group.anyone(), group.everyone());
+        for (question, count) in group.counts().iter().enumerate() {
+            let frequency = &mut frequencies[question];
+            frequency.people += count;
+            frequency.anyone += anyone.contains(question) as usize;
+            frequency.everyone += everyone.contains(question) as usize;
+        }
+    }
+
+    return frequencies;
+}
+
+// The person in a group whose answers are furthest from everyone else's.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Outlier {
+    // The group, counting from 1, and the line the person's answers are on.
+    pub group: usize,
+    pub line: usize,
+    pub answers: Answers,
+    // How many questions, on average, only one of the person and one of the others answered.
+    pub distance: f64,
+}
+
+fn outlier(index: usize, group: &Group) -> Option<Outlier> {
+    if group.people.len() < 2 {
+        return None;
+    }
+
+    let others = (group.people.len() - 1) as f64;
+    let mut furthest: Option<Outlier> = None;
+    for (person, answers) in group.people.iter().enumerate() {
+        let total: usize = group.people.iter().map(|other| answers.distance(*other)).sum();
+        let distance = total as f64 / others;
+        if distance > furthest.map_or(0.0, |furthest| furthest.distance) {
+            furthest = Some(Outlier { group: index + 1, line: group.line + person, answers: *answers, distance: distance });
+        }
+    }
+
+    return furthest;
+}
+
+// The outlier of each group whose people didn't all give the same answers, furthest first.
+pub fn outliers(groups: &Vec<Group>) -> Vec<Outlier> {
+    let mut outliers: Vec<Outlier> = groups.iter().enumerate().filter_map(|(index, group)| outlier(index, group)).collect();
+    outliers.sort_by(|a, b| b.distance.partial_cmp(&a.distance).unwrap_or(Ordering::Equal).then(a.group.cmp(&b.group)));
+    return outliers;
+}
+
+pub fn frequency_json(frequency: &Frequency) -> Value {
+    return json!({ "question": frequency.question.to_string(), "people": frequency.people, "anyone": frequency.anyone, "everyone": frequency.everyone });
+}
+
+pub fn outlier_json(outlier: &Outlier) -> Value {
+    return json!({ "group": outlier.group, "line": outlier.line, "answers": outlier.answers.to_string(), "distance": outlier.distance });
+}
@@ -0,0 +1,61 @@
+use std::time::Instant;
+
+use aoc_common::cli::DayOption;
+use aoc_common::json;
+
+use crate::analysis::{frequencies, frequency_json, outlier_json, outliers};
+use crate::{count_with_details, parse_file, Query};
+
+pub const OPTIONS: &[DayOption] = &[
+    DayOption { name: "query", value: Some("<any|all|none|exactly=<k>|at-least=<k>>"), description: "Count the questions in each group answered by this many of its people instead" },
+    DayOption { name: "frequencies", value: None, description: "List how often each question was answered across the groups" },
+    DayOption { name: "outliers", value: Some("<count>"), description: "List the people whose answers differ most from the rest of their group" },
+];
+
+pub fn main(part: usize, default_query: Query, default_description: &str) {
+    let options = aoc_common::cli::parse_args_with(OPTIONS);
+    let (query, description) = match options.value("query") {
+        None => (default_query, String::from(default_description)),
+        Some(name) => match Query::from_name(name) {
+            None => aoc_common::exit_with_error(format!("Unknown query '{}', expected any, all, none, exactly=<k> or at-least=<k>", name)),
+            Some(query) => (query, format!("Sum of the questions matching {} in each group", query)),
+        },
+    };
+    let outlier_count = options.value("outliers").map(|count| match count.parse::<usize>() {
+        Err(_) => aoc_common::exit_with_error("--outliers expects a number of people"),
+        Ok(count) => count,
+    });
+
+    let start = Instant::now();
+    let groups = match parse_file(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(groups) => groups
+    };
+    let (answer_sum, mut details) = count_with_details(&groups, query);
+
+    let frequencies = if options.flag("frequencies") { frequencies(&groups) } else { Vec::new() };
+    let outliers = match outlier_count {
+        None => Vec::new(),
+        Some(count) => outliers(&groups).into_iter().take(count).collect(),
+    };
+
+    if options.json {
+        details["input"] = json!(options.input.to_string());
+        details["description"] = json!(description);
+        if options.flag("frequencies") {
+            details["frequencies"] = frequencies.iter().map(frequency_json).collect();
+        }
+        if outlier_count.is_some() {
+            details["outliers"] = outliers.iter().map(outlier_json).collect();
+        }
+        aoc_common::output::print_json(6, part, &answer_sum, start.elapsed(), details);
+    } else {
+        for frequency in &frequencies {
+            println!("{}: {} people, anyone in {} groups, everyone in {} groups", frequency.question, frequency.people, frequency.anyone, frequency.everyone);
+        }
+        for outlier in &outliers {
+            println!("Group {} line {}: \"{}\" differs from the others by {:.2} questions", outlier.group, outlier.line, outlier.answers, outlier.distance);
+        }
+        print!("Answer Sum: {}", answer_sum);
+    }
+}
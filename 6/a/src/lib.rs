@@ -0,0 +1,207 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Record, Value};
+
+pub mod analysis;
+pub mod command;
+
+pub const QUESTIONS: usize = 26;
+
+// The questions one person answered "yes" to, a bit per question with 'a' as the lowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Answers(pub u32);
+
+impl Answers {
+    pub const NONE: Answers = Answers(0);
+    pub const ALL: Answers = Answers((1 << QUESTIONS) - 1);
+
+    pub fn parse(line: &str) -> Option<Answers> {
+        let mut bits = 0;
+        for answer in line.bytes() {
+            if !answer.is_ascii_lowercase() {
+                return None;
+            }
+            bits |= 1 << (answer - b'a');
+        }
+
+        return Some(Answers(bits));
+    }
+
+    pub fn contains(&self, question: usize) -> bool {
+        return (self.0 >> question) & 1 == 1;
+    }
+
+    pub fn count(&self) -> usize {
+        return self.0.count_ones() as usize;
+    }
+
+    // How many questions only one of the two answered.
+    pub fn distance(&self, other: Answers) -> usize {
+        return (self.0 ^ other.0).count_ones() as usize;
+    }
+
+    pub fn questions(&self) -> impl Iterator<Item = usize> + '_ {
+        return (0..QUESTIONS).filter(move |question| self.contains(*question));
+    }
+}
+
+// Displays the letters of the questions answered, in order.
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.questions().map(|question| (b'a' + question as u8) as char).collect::<String>());
+    }
+}
+
+// One group's forms, a person per line. `line` is the line the group starts on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub line: usize,
+    pub people: Vec<Answers>,
+}
+
+// Which questions of a group to count, by how many of its people answered them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Query {
+    Any,
+    All,
+    None,
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+impl Query {
+    // "any", "all", "none", "exactly=<k>" or "at-least=<k>".
+    pub fn from_name(name: &str) -> Option<Query> {
+        return match name.split_once('=') {
+            None if name == "any" => Some(Query::Any),
+            None if name == "all" => Some(Query::All),
+            None if name == "none" => Some(Query::None),
+            Some(("exactly", people)) => people.parse().ok().map(Query::Exactly),
+            Some(("at-least", people)) => people.parse().ok().map(Query::AtLeast),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Query::Any => write!(f, "any"),
+            Query::All => write!(f, "all"),
+            Query::None => write!(f, "none"),
+            Query::Exactly(people) => write!(f, "exactly={}", people),
+            Query::AtLeast(people) => write!(f, "at-least={}", people),
+        };
+    }
+}
+
+impl Group {
+    pub fn anyone(&self) -> Answers {
+        return Answers(self.people.iter().fold(0, |bits, answers| bits | answers.0));
+    }
+
+    pub fn everyone(&self) -> Answers {
+        return Answers(self.people.iter().fold(Answers::ALL.0, |bits, answers| bits & answers.0));
+    }
+
+    // How many people answered each question.
+    pub fn counts(&self) -> [usize; QUESTIONS] {
+        let mut counts = [0; QUESTIONS];
+        for answers in &self.people {
+            for question in answers.questions() {
+                counts[question] += 1;
+            }
+        }
+
+        return counts;
+    }
+
+    // The questions that match the query. Any, all and none don't need counting, so they stay quick.
+    pub fn matching(&self, query: Query) -> Answers {
+        let by_count = |matches: &dyn Fn(usize) -> bool| {
+            let counts = self.counts();
+            Answers((0..QUESTIONS).filter(|question| matches(counts[*question])).fold(0, |bits, question| bits | (1 << question)))
+        };
+
+        return match query {
+            Query::Any => self.anyone(),
+            Query::All => self.everyone(),
+            Query::None => Answers(Answers::ALL.0 & !self.anyone().0),
+            Query::Exactly(people) => by_count(&|count| count == people),
+            Query::AtLeast(people) => by_count(&|count| count >= people),
+        };
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+fn parse_group(record: &Record) -> Result<Group, Error> {
+    let mut people = Vec::with_capacity(record.lines.len());
+    for (offset, line) in record.lines.iter().enumerate() {
+        match Answers::parse(line) {
+            None => return Err(Error::Malformed { line: record.line + offset, text: String::from(line), expected: "the letters a to z" }),
+            Some(answers) => people.push(answers),
+        }
+    }
+
+    return Ok(Group { line: record.line, people: people });
+}
+
+pub fn parse_file(input: &Input) -> Result<Vec<Group>, Error> {
+    let records = aoc_common::read_records(input)?;
+    return records.iter().map(|record| parse_group(record)).collect();
+}
+
+// The questions that match the query in each group, added up over the groups.
+pub fn count(groups: &Vec<Group>, query: Query) -> usize {
+    return groups.iter().map(|group| group.matching(query).count()).sum();
+}
+
+// The sum along with the query and how many questions it matched in each group.
+pub fn count_with_details(groups: &Vec<Group>, query: Query) -> (usize, Value) {
+    let counts: Vec<usize> = groups.iter().map(|group| group.matching(query).count()).collect();
+    return (counts.iter().sum(), json!({ "query": query.to_string(), "groups": counts }));
+}
+
+pub fn solve(groups: &Vec<Group>) -> Result<usize, Error> {
+    return Ok(count(groups, Query::Any));
+}
+
+pub fn part1(input: &Input) -> Result<usize, Error> {
+    let groups = parse_file(input)?;
+    return solve(&groups);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let groups = parse_file(input)?;
+    return Ok(count_with_details(&groups, Query::Any));
+}
@@ -0,0 +1,18 @@
+use aoc_common::{Input, Value};
+
+pub use day6a::analysis::{frequencies, outliers, Frequency, Outlier};
+pub use day6a::{command, count, count_with_details, parse_file, Answers, Error, Group, Query};
+
+pub fn solve(groups: &Vec<Group>) -> Result<usize, Error> {
+    return Ok(count(groups, Query::All));
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let groups = parse_file(input)?;
+    return solve(&groups);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let groups = parse_file(input)?;
+    return Ok(count_with_details(&groups, Query::All));
+}
@@ -0,0 +1,70 @@
+use std::time::Instant;
+
+use aoc_common::cli::DayOption;
+use aoc_common::{json, Value};
+
+use crate::export::{select, to_dot, to_json, tree, Format};
+use crate::graph::{BagGraph, Counter};
+use crate::validate::check;
+use crate::{parse_file, Error, SHINY_GOLD};
+
+pub const OPTIONS: &[DayOption] = &[
+    DayOption { name: "bag", value: Some("<colour>"), description: "Ask about bags of this colour instead of shiny gold" },
+    DayOption { name: "export", value: Some("<dot|json|tree>"), description: "Print the rules as a graph in this format instead" },
+    DayOption { name: "from", value: Some("<colour>"), description: "Only export the bags inside bags of this colour, and start --export tree there" },
+    DayOption { name: "to", value: Some("<colour>"), description: "Only export the bags that can hold bags of this colour" },
+];
+
+fn export(graph: &BagGraph, format: Format, from: Option<&str>, to: Option<&str>, bag: &str) {
+    let id = |name: &str| match graph.id(name) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(id) => id
+    };
+    let selected = select(graph, from.map(id), to.map(id));
+
+    match format {
+        Format::Dot => print!("{}", to_dot(graph, &selected)),
+        Format::Json => println!("{}", to_json(graph, &selected)),
+        // A tree needs somewhere to start, so without --from it starts at the bag being asked about.
+        Format::Tree => println!("{}", tree(graph, &selected, id(from.unwrap_or(bag))).join("\n")),
+    }
+}
+
+// Counts the bags for one part, along with the details for --json.
+pub type Count = fn(&mut Counter, &str) -> Result<(usize, Value), Error>;
+
+pub fn main(part: usize, count: Count, describe: fn(&str) -> String) {
+    let options = aoc_common::cli::parse_args_with(OPTIONS);
+    let bag = options.value("bag").unwrap_or(SHINY_GOLD);
+    let export_format = options.value("export").map(|name| match Format::from_name(name) {
+        None => aoc_common::exit_with_error(format!("Unknown export format '{}', expected dot, json or tree", name)),
+        Some(format) => format,
+    });
+
+    let start = Instant::now();
+    let graph = match parse_file(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(graph) => graph
+    };
+    // Check the whole graph first, so a bad rule anywhere is reported even if the query wouldn't reach it.
+    let mut counter = match check(&graph) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(counter) => counter
+    };
+    if let Some(format) = export_format {
+        export(&graph, format, options.value("from"), options.value("to"), bag);
+        return;
+    }
+    let (bag_count, mut details) = match count(&mut counter, bag) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(found) => found
+    };
+
+    if options.json {
+        details["input"] = json!(options.input.to_string());
+        details["description"] = json!(describe(bag));
+        aoc_common::output::print_json(7, part, &bag_count, start.elapsed(), details);
+    } else {
+        print!("Bag Count: {}", bag_count);
+    }
+}
@@ -0,0 +1,91 @@
+use aoc_common::{json, Value};
+
+use crate::graph::{BagGraph, BagId};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Dot,
+    Json,
+    Tree,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Format> {
+        return match name {
+            "dot" => Some(Format::Dot),
+            "json" => Some(Format::Json),
+            "tree" => Some(Format::Tree),
+            _ => None,
+        };
+    }
+}
+
+// Which bags to export: all of them, or only the ones inside `from` and that can hold `to`, including those two.
+// With both, that's the bags on the way from one to the other.
+pub fn select(graph: &BagGraph, from: Option<BagId>, to: Option<BagId>) -> Vec<bool> {
+    let mut selected = vec![true; graph.len()];
+    let mut keep = |bag: BagId, reachable: Vec<BagId>| {
+        let mut found = vec![false; graph.len()];
+        for id in reachable.into_iter().chain(std::iter::once(bag)) {
+            found[id] = true;
+        }
+        for (selected, found) in selected.iter_mut().zip(found) {
+            *selected &= found;
+        }
+    };
+
+    if let Some(from) = from {
+        keep(from, graph.nested_bags(from));
+    }
+    if let Some(to) = to {
+        keep(to, graph.outer_bags(to));
+    }
+
+    return selected;
+}
+
+// The selected bags' contents, leaving out the edges to bags that aren't selected.
+fn edges<'a>(graph: &'a BagGraph, selected: &'a Vec<bool>, id: BagId) -> impl Iterator<Item = &'a (BagId, usize)> + 'a {
+    return graph.contents(id).iter().filter(move |(inner, _)| selected[*inner]);
+}
+
+// A Graphviz digraph with an edge from each bag to each bag inside it, weighted and labelled with how many.
+pub fn to_dot(graph: &BagGraph, selected: &Vec<bool>) -> String {
+    let mut lines = vec![String::from("digraph bags {")];
+    for id in (0..graph.len()).filter(|id| selected[*id]) {
+        lines.push(format!("    \"{}\";", graph.name(id)));
+        for (inner, count) in edges(graph, selected, id) {
+            lines.push(format!("    \"{}\" -> \"{}\" [weight={}, label=\"{}\"];", graph.name(id), graph.name(*inner), count, count));
+        }
+    }
+    lines.push(String::from("}"));
+
+    return lines.join("\n") + "\n";
+}
+
+// An object with a key for each bag, listing the bags inside it and how many.
+pub fn to_json(graph: &BagGraph, selected: &Vec<bool>) -> Value {
+    let mut adjacency = json!({});
+    for id in (0..graph.len()).filter(|id| selected[*id]) {
+        adjacency[graph.name(id)] = edges(graph, selected, id).map(|(inner, count)| json!({ "bag": graph.name(*inner), "count": count })).collect();
+    }
+
+    return adjacency;
+}
+
+fn expand(graph: &BagGraph, selected: &Vec<bool>, id: BagId, bags: usize, depth: usize, lines: &mut Vec<String>) {
+    for (inner, count) in edges(graph, selected, id) {
+        let total = bags * count;
+        let working = if bags > 1 { format!(" ({} x {})", bags, count) } else { String::new() };
+        lines.push(format!("{}{} {}{}", "  ".repeat(depth), total, graph.name(*inner), working));
+        expand(graph, selected, *inner, total, depth + 1, lines);
+    }
+}
+
+// The bag, then every bag inside it indented under the bag it goes in, with how many of them there are in all. The
+// rules have to have been checked first, so there are no cycles and the counts fit.
+pub fn tree(graph: &BagGraph, selected: &Vec<bool>, id: BagId) -> Vec<String> {
+    let mut lines = vec![String::from(graph.name(id))];
+    expand(graph, selected, id, 1, 1, &mut lines);
+    return lines;
+}
@@ -0,0 +1,210 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::Error;
+
+// Bags are interned as they're read, so the graph can refer to them by index.
+pub type BagId = usize;
+
+// One line of the rules: the bag it's about, and how many of which bags go directly inside it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub line: usize,
+    pub bag: BagId,
+    pub contents: Vec<(BagId, usize)>,
+}
+
+// Every bag named in the rules, with edges weighted by count in both directions: the bags that go inside each bag,
+// and the bags each bag can go inside. A bag's first rule is the one that counts.
+#[derive(Debug, Default)]
+pub struct BagGraph {
+    names: Vec<String>,
+    ids: HashMap<String, BagId>,
+    pub rules: Vec<Rule>,
+    // Which of `rules` gives each bag's contents, if any does.
+    defined_by: Vec<Option<usize>>,
+    contents: Vec<Vec<(BagId, usize)>>,
+    containers: Vec<Vec<(BagId, usize)>>,
+}
+
+impl BagGraph {
+    pub fn intern(&mut self, name: &str) -> BagId {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+
+        let id = self.names.len();
+        self.names.push(String::from(name));
+        self.ids.insert(String::from(name), id);
+        self.defined_by.push(None);
+        self.contents.push(Vec::new());
+        self.containers.push(Vec::new());
+        return id;
+    }
+
+    pub fn add_rule(&mut self, rule: Rule) {
+        if self.defined_by[rule.bag].is_none() {
+            self.defined_by[rule.bag] = Some(self.rules.len());
+            for (inner, count) in &rule.contents {
+                self.contents[rule.bag].push((*inner, *count));
+                self.containers[*inner].push((rule.bag, *count));
+            }
+        }
+        self.rules.push(rule);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.names.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.names.is_empty();
+    }
+
+    pub fn id(&self, name: &str) -> Result<BagId, Error> {
+        return self.ids.get(name).copied().ok_or_else(|| Error::UnknownBag(String::from(name)));
+    }
+
+    pub fn name(&self, id: BagId) -> &str {
+        return &self.names[id];
+    }
+
+    // The rule that gives the bag's contents, or None if the bag is only ever mentioned inside other bags.
+    pub fn rule(&self, id: BagId) -> Option<&Rule> {
+        return self.defined_by[id].map(|index| &self.rules[index]);
+    }
+
+    pub fn contents(&self, id: BagId) -> &[(BagId, usize)] {
+        return &self.contents[id];
+    }
+
+    pub fn containers(&self, id: BagId) -> &[(BagId, usize)] {
+        return &self.containers[id];
+    }
+
+    // The bags found by following `edges` from the bag, breadth first, not counting the bag itself unless it's in a cycle.
+    fn reachable(&self, id: BagId, edges: fn(&BagGraph, BagId) -> &[(BagId, usize)]) -> Vec<BagId> {
+        let mut seen = vec![false; self.len()];
+        let mut found = Vec::new();
+        let mut bags_to_check = VecDeque::new();
+        bags_to_check.push_back(id);
+
+        while let Some(next_bag) = bags_to_check.pop_front() {
+            for (bag, _) in edges(self, next_bag) {
+                if !seen[*bag] {
+                    seen[*bag] = true;
+                    found.push(*bag);
+                    bags_to_check.push_back(*bag);
+                }
+            }
+        }
+
+        return found;
+    }
+
+    // Every bag that can hold the bag, directly or inside other bags, in the order they're found going outwards.
+    pub fn outer_bags(&self, id: BagId) -> Vec<BagId> {
+        return self.reachable(id, BagGraph::containers);
+    }
+
+    // Every colour of bag that ends up inside the bag, in the order they're found going inwards.
+    pub fn nested_bags(&self, id: BagId) -> Vec<BagId> {
+        return self.reachable(id, BagGraph::contents);
+    }
+
+    pub fn counter(&self) -> Counter<'_> {
+        return Counter { graph: self, memo: vec![Memo::Unknown; self.len()] };
+    }
+
+    // A counter that starts out knowing the counts that are Some.
+    pub fn counter_with(&self, counts: &[Option<usize>]) -> Counter<'_> {
+        let memo = counts.iter().map(|count| count.map_or(Memo::Unknown, Memo::Known)).collect();
+        return Counter { graph: self, memo: memo };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Memo {
+    Unknown,
+    Counting,
+    Known(usize),
+}
+
+// Counts the bags inside bags, remembering the count for every bag it goes through so later queries are quick.
+pub struct Counter<'a> {
+    graph: &'a BagGraph,
+    memo: Vec<Memo>,
+}
+
+impl<'a> Counter<'a> {
+    pub fn graph(&self) -> &'a BagGraph {
+        return self.graph;
+    }
+
+    // How many bags end up inside the bag, counting the bags inside those too.
+    pub fn inner_bags(&mut self, id: BagId) -> Result<usize, Error> {
+        let result = self.count(id);
+        if result.is_err() {
+            // Forget the bags that were still being counted, so they're counted again next time.
+            for memo in self.memo.iter_mut().filter(|memo| **memo == Memo::Counting) {
+                *memo = Memo::Unknown;
+            }
+        }
+        return result;
+    }
+
+    // `total` plus `count` bags that each hold `bags` bags, for the contents of `bag`.
+    fn add(&self, bag: BagId, total: usize, count: usize, bags: usize) -> Result<usize, Error> {
+        return match bags.checked_add(1).and_then(|bags| bags.checked_mul(count)).and_then(|bags| total.checked_add(bags)) {
+            None => Err(Error::TooManyBags(String::from(self.graph.name(bag)))),
+            Some(total) => Ok(total)
+        };
+    }
+
+    // Works through the contents with a path of its own instead of recursing, so a long chain of bags can't overflow
+    // the stack.
+    fn count(&mut self, id: BagId) -> Result<usize, Error> {
+        if let Memo::Known(count) = self.memo[id] {
+            return Ok(count);
+        }
+        if self.graph.rule(id).is_none() {
+            return Err(Error::UnknownBag(String::from(self.graph.name(id))));
+        }
+
+        // The bags being counted, outermost first, with how many of their contents have been looked at and the bags
+        // inside those so far.
+        let mut path: Vec<(BagId, usize, usize)> = vec![(id, 0, 0)];
+        self.memo[id] = Memo::Counting;
+        loop {
+            let last = path.len() - 1;
+            let (bag, next, total) = path[last];
+            let contents = self.graph.contents(bag);
+            if next == contents.len() {
+                self.memo[bag] = Memo::Known(total);
+                path.pop();
+                match path.last_mut() {
+                    None => return Ok(total),
+                    Some((outer, next, outer_total)) => *outer_total = self.add(*outer, *outer_total, self.graph.contents(*outer)[*next - 1].1, total)?,
+                }
+                continue;
+            }
+
+            let (inner, count) = contents[next];
+            path[last].1 += 1;
+            match self.memo[inner] {
+                Memo::Known(bags) => path[last].2 = self.add(bag, total, count, bags)?,
+                Memo::Counting => {
+                    let start = path.iter().position(|(open, _, _)| *open == inner).unwrap_or(0);
+                    let cycle = path[start..].iter().map(|(open, _, _)| *open).chain(std::iter::once(inner));
+                    return Err(Error::Cycle(cycle.map(|bag| String::from(self.graph.name(bag))).collect()));
+                },
+                Memo::Unknown => {
+                    if self.graph.rule(inner).is_none() {
+                        return Err(Error::UnknownBag(String::from(self.graph.name(inner))));
+                    }
+                    self.memo[inner] = Memo::Counting;
+                    path.push((inner, 0, 0));
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+use regex::Regex;
+use lazy_static::lazy_static;
+use log::trace;
+
+pub mod command;
+pub mod export;
+pub mod graph;
+pub mod validate;
+
+use graph::{BagGraph, Counter, Rule};
+use validate::Problem;
+
+lazy_static! {
+    static ref RULE_REGEX: Regex = Regex::new(r"^(?P<name>[a-z]+(?: [a-z]+)*) bags contain (?P<contents>.+)\.$").unwrap();
+    static ref CONTENTS_REGEX: Regex = Regex::new(r"^(?P<count>\d+) (?P<name>[a-z]+(?: [a-z]+)*) bags?$").unwrap();
+}
+
+// The bag the puzzle asks about.
+pub const SHINY_GOLD: &str = "shiny gold";
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Malformed { line: usize, text: String, expected: &'static str },
+    UnknownBag(String),
+    // The bags in the cycle, starting and ending with the same one.
+    Cycle(Vec<String>),
+    TooManyBags(String),
+    Invalid(Vec<Problem>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::Malformed { line, text, expected } => write!(f, "Couldn't parse line {} (\"{}\"): expected {}", line, text, expected),
+            Error::UnknownBag(name) => write!(f, "There is no rule for {} bags", name),
+            Error::Cycle(path) => write!(f, "Bags end up inside themselves: {}", path.join(" -> ")),
+            Error::TooManyBags(name) => write!(f, "There are too many bags inside {} bags to count", name),
+            Error::Invalid(problems) => {
+                write!(f, "The rules have {} problem{}:", problems.len(), if problems.len() == 1 { "" } else { "s" })?;
+                for problem in problems {
+                    write!(f, "\n  {}", problem)?;
+                }
+                return Ok(());
+            },
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+const RULE_FORM: &str = "\"<colour> bags contain <count> <colour> bags, ...\" or \"<colour> bags contain no other bags.\"";
+
+fn parse_rule(line_number: usize, value: &str, graph: &mut BagGraph) -> Result<(), Error> {
+    trace!("{}", value);
+
+    let malformed = || Error::Malformed { line: line_number, text: String::from(value), expected: RULE_FORM };
+    let captures = RULE_REGEX.captures(value).ok_or_else(malformed)?;
+
+    let bag = graph.intern(&captures["name"]);
+    let mut contents = Vec::new();
+    if &captures["contents"] != "no other bags" {
+        for inner_bag in captures["contents"].split(", ") {
+            let inner_captures = CONTENTS_REGEX.captures(inner_bag).ok_or_else(malformed)?;
+            let count = inner_captures["count"].parse::<usize>().map_err(|_| malformed())?;
+            contents.push((graph.intern(&inner_captures["name"]), count));
+        }
+    }
+
+    graph.add_rule(Rule { line: line_number, bag: bag, contents: contents });
+    return Ok(());
+}
+
+pub fn parse_file(input: &Input) -> Result<BagGraph, Error> {
+    let lines = aoc_common::read_lines(input)?;
+
+    let mut graph = BagGraph::default();
+    for (index, line) in lines.iter().enumerate() {
+        parse_rule(index + 1, line, &mut graph)?;
+    }
+
+    return Ok(graph);
+}
+
+// How many different bags can end up holding the bag.
+pub fn outer_bag_count(graph: &BagGraph, name: &str) -> Result<usize, Error> {
+    return Ok(graph.outer_bags(graph.id(name)?).len());
+}
+
+// The count along with the colours of those bags.
+pub fn outer_bag_count_with_details(graph: &BagGraph, name: &str) -> Result<(usize, Value), Error> {
+    let outer_bags: Vec<&str> = graph.outer_bags(graph.id(name)?).into_iter().map(|id| graph.name(id)).collect();
+    return Ok((outer_bags.len(), json!({ "bag": name, "outer_bags": outer_bags })));
+}
+
+// How many bags end up inside the bag. The counter remembers what it's counted, so keep it for the next query.
+pub fn inner_bag_count(counter: &mut Counter, name: &str) -> Result<usize, Error> {
+    let id = counter.graph().id(name)?;
+    return counter.inner_bags(id);
+}
+
+// The count along with the bags directly inside the bag, and how many bags each of those holds.
+pub fn inner_bag_count_with_details(counter: &mut Counter, name: &str) -> Result<(usize, Value), Error> {
+    let graph = counter.graph();
+    let id = graph.id(name)?;
+    let bag_count = counter.inner_bags(id)?;
+    let mut contents = Vec::new();
+    for (inner, count) in graph.contents(id) {
+        contents.push(json!({ "bag": graph.name(*inner), "count": count, "inner_bags": counter.inner_bags(*inner)? }));
+    }
+
+    return Ok((bag_count, json!({ "bag": name, "contents": contents })));
+}
+
+pub fn solve(graph: &BagGraph) -> Result<usize, Error> {
+    return outer_bag_count(graph, SHINY_GOLD);
+}
+
+pub fn part1(input: &Input) -> Result<usize, Error> {
+    let graph = parse_file(input)?;
+    validate::check(&graph)?;
+    return solve(&graph);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let graph = parse_file(input)?;
+    validate::check(&graph)?;
+    return outer_bag_count_with_details(&graph, SHINY_GOLD);
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::graph::{BagGraph, BagId, Counter};
+use crate::Error;
+
+// Something wrong with the rules that would make a query fail, or quietly give the wrong answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Problem {
+    // The bags in the cycle, starting and ending with the same one.
+    Cycle(Vec<String>),
+    // A bag that goes inside other bags, on these lines, but has no rule of its own.
+    Undefined { bag: String, lines: Vec<usize> },
+    // A bag with a rule on more than one line. Only the first one is used.
+    Duplicate { bag: String, lines: Vec<usize> },
+    // A bag with more bags inside it than fit in a usize.
+    TooManyBags { bag: String },
+}
+
+// "line 3" or "lines 3, 7".
+fn format_lines(lines: &Vec<usize>) -> String {
+    let numbers = lines.iter().map(|line| line.to_string()).collect::<Vec<String>>().join(", ");
+    return format!("{} {}", if lines.len() == 1 { "line" } else { "lines" }, numbers);
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Problem::Cycle(path) => write!(f, "Bags end up inside themselves: {}", path.join(" -> ")),
+            Problem::Undefined { bag, lines } => write!(f, "There is no rule for {} bags, which go inside other bags on {}", bag, format_lines(lines)),
+            Problem::Duplicate { bag, lines } => write!(f, "There are rules for {} bags on {}", bag, format_lines(lines)),
+            Problem::TooManyBags { bag } => write!(f, "There are too many bags inside {} bags to count", bag),
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Visit {
+    NotYet,
+    // On the path from the bag the search started at.
+    Open,
+    // Finished, with the number of bags inside, or None if they can't be counted.
+    Done(Option<usize>),
+}
+
+// Looks for cycles and counts the bags inside every bag, without recursing, so a long chain of bags can't overflow the
+// stack. Bags without a rule count as empty here, since they're reported on their own. Gives back every bag's count, or
+// None for the bags that can't be counted.
+fn check_contents(graph: &BagGraph, problems: &mut Vec<Problem>) -> Vec<Option<usize>> {
+    let mut visits = vec![Visit::NotYet; graph.len()];
+    for root in 0..graph.len() {
+        if visits[root] != Visit::NotYet {
+            continue;
+        }
+
+        // Each bag on the path, with how many of its contents have been looked at.
+        let mut path: Vec<(BagId, usize)> = vec![(root, 0)];
+        visits[root] = Visit::Open;
+        while let Some((bag, next)) = path.last_mut() {
+            let (bag, contents) = (*bag, graph.contents(*bag));
+            if *next < contents.len() {
+                let inner = contents[*next].0;
+                *next += 1;
+                match visits[inner] {
+                    Visit::NotYet => {
+                        visits[inner] = Visit::Open;
+                        path.push((inner, 0));
+                    },
+                    Visit::Open => {
+                        let start = path.iter().position(|(open, _)| *open == inner).unwrap_or(0);
+                        let cycle = path[start..].iter().map(|(open, _)| *open).chain(std::iter::once(inner));
+                        problems.push(Problem::Cycle(cycle.map(|bag| String::from(graph.name(bag))).collect()));
+                    },
+                    Visit::Done(_) => (),
+                }
+                continue;
+            }
+
+            // Every bag inside this one is done, unless it's part of a cycle, and then it stays open and counts as
+            // uncountable.
+            let mut total = Some(0usize);
+            let mut countable = true;
+            for (inner, count) in contents {
+                let bags = match visits[*inner] {
+                    Visit::Done(Some(bags)) => bags.checked_add(1).and_then(|bags| bags.checked_mul(*count)),
+                    _ => {
+                        countable = false;
+                        None
+                    },
+                };
+                total = total.and_then(|total| bags.and_then(|bags| total.checked_add(bags)));
+            }
+            if countable && total.is_none() {
+                problems.push(Problem::TooManyBags { bag: String::from(graph.name(bag)) });
+            }
+            visits[bag] = Visit::Done(total);
+            path.pop();
+        }
+    }
+
+    return visits.into_iter().map(|visit| if let Visit::Done(total) = visit { total } else { None }).collect();
+}
+
+// Everything wrong with the rules, along with the number of bags inside every bag.
+fn problems_and_counts(graph: &BagGraph) -> (Vec<Problem>, Vec<Option<usize>>) {
+    let mut cycles = Vec::new();
+    let counts = check_contents(graph, &mut cycles);
+    let (cycles, too_many): (Vec<Problem>, Vec<Problem>) = cycles.into_iter().partition(|problem| matches!(problem, Problem::Cycle(_)));
+
+    let mut mentions: BTreeMap<BagId, Vec<usize>> = BTreeMap::new();
+    let mut definitions: BTreeMap<BagId, Vec<usize>> = BTreeMap::new();
+    for rule in &graph.rules {
+        definitions.entry(rule.bag).or_default().push(rule.line);
+        for (inner, _) in &rule.contents {
+            mentions.entry(*inner).or_default().push(rule.line);
+        }
+    }
+
+    let mut problems = cycles;
+    for (bag, lines) in mentions {
+        if graph.rule(bag).is_none() {
+            problems.push(Problem::Undefined { bag: String::from(graph.name(bag)), lines: lines });
+        }
+    }
+    for (bag, lines) in definitions {
+        if lines.len() > 1 {
+            problems.push(Problem::Duplicate { bag: String::from(graph.name(bag)), lines: lines });
+        }
+    }
+    problems.extend(too_many);
+
+    return (problems, counts);
+}
+
+// Everything wrong with the rules: cycles first, in the order they're found, then bags without a rule, bags with more
+// than one rule and bags with too many bags inside them.
+pub fn validate(graph: &BagGraph) -> Vec<Problem> {
+    return problems_and_counts(graph).0;
+}
+
+// Fails with every problem in the rules, if there are any. Otherwise gives back a counter that already knows how many
+// bags are inside every bag, since checking the rules has to count them anyway.
+pub fn check(graph: &BagGraph) -> Result<Counter<'_>, Error> {
+    let (problems, counts) = problems_and_counts(graph);
+    if !problems.is_empty() {
+        return Err(Error::Invalid(problems));
+    }
+
+    return Ok(graph.counter_with(&counts));
+}
@@ -0,0 +1,20 @@
+use aoc_common::{Input, Value};
+
+pub use day7a::export::{select, to_dot, to_json, tree};
+pub use day7a::graph::{BagGraph, BagId, Counter, Rule};
+pub use day7a::validate::{check, validate, Problem};
+pub use day7a::{command, inner_bag_count, inner_bag_count_with_details, outer_bag_count, parse_file, Error, SHINY_GOLD};
+
+pub fn solve(graph: &BagGraph) -> Result<usize, Error> {
+    return inner_bag_count(&mut graph.counter(), SHINY_GOLD);
+}
+
+pub fn part2(input: &Input) -> Result<usize, Error> {
+    let graph = parse_file(input)?;
+    return inner_bag_count(&mut check(&graph)?, SHINY_GOLD);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(usize, Value), Error> {
+    let graph = parse_file(input)?;
+    return inner_bag_count_with_details(&mut check(&graph)?, SHINY_GOLD);
+}
@@ -0,0 +1,70 @@
+use std::io::{self, BufRead, Write};
+use std::time::Instant;
+
+use aoc_common::cli::DayOption;
+use aoc_common::{json, Value};
+
+use day8vm::debugger::Debugger;
+use day8vm::vm::Program;
+
+use crate::{parse_file, Error};
+
+pub const OPTIONS: &[DayOption] = &[
+    DayOption { name: "debug", value: None, description: "Step through the program instead, reading debugger commands from stdin" },
+];
+
+// Reads commands until stdin runs out or one of them is quit.
+fn debug(program: Program) {
+    let mut debugger = Debugger::new(program);
+    println!("Loaded {} instructions, type help for the commands", debugger.machine.program.len());
+
+    let stdin = io::stdin();
+    while !debugger.quit {
+        print!("(debug) ");
+        if let Err(why) = io::stdout().flush() {
+            aoc_common::exit_with_error(why);
+        }
+
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Err(why) => aoc_common::exit_with_error(why),
+            Ok(0) => {
+                println!();
+                return;
+            },
+            Ok(_) => (),
+        }
+        for output in debugger.execute(&line) {
+            println!("{}", output);
+        }
+    }
+}
+
+pub fn main(part: usize, solve: fn(&Program) -> Result<(i64, Value), Error>, description: &str) {
+    let options = aoc_common::cli::parse_args_with(OPTIONS);
+    if options.flag("debug") && options.input.is_stdin() {
+        aoc_common::exit_with_error("--debug reads its commands from stdin, so the program has to come from a file or --input");
+    }
+
+    let start = Instant::now();
+    let program = match parse_file(&options.input) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(program) => program
+    };
+    if options.flag("debug") {
+        debug(program);
+        return;
+    }
+
+    let (accumulator, mut details) = match solve(&program) {
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(found) => found
+    };
+    if options.json {
+        details["input"] = json!(options.input.to_string());
+        details["description"] = json!(description);
+        aoc_common::output::print_json(8, part, &accumulator, start.elapsed(), details);
+    } else {
+        print!("Accumulator: {}", accumulator);
+    }
+}
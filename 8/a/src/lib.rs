@@ -0,0 +1,69 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, Value};
+use day8vm::vm::{Machine, Program, Termination, OPCODES};
+
+pub mod command;
+
+#[derive(Debug)]
+pub enum Error {
+    Program(day8vm::Error),
+    NoLoop,
+    NoFix,
+    Stopped(Termination),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Program(why) => write!(f, "{}", why),
+            Error::NoLoop => write!(f, "The program finished without repeating an instruction"),
+            Error::NoFix => write!(f, "Swapping a single jmp or nop doesn't make the program finish"),
+            Error::Stopped(termination) => write!(f, "The program stopped early: {}", termination),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Program(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<day8vm::Error> for Error {
+    fn from(why: day8vm::Error) -> Error {
+        return Error::Program(why);
+    }
+}
+
+pub fn parse_file(input: &Input) -> Result<Program, Error> {
+    return Ok(Program::parse_with(input, OPCODES)?);
+}
+
+pub fn solve(program: &Program) -> Result<i64, Error> {
+    return Ok(solve_with_details(program)?.0);
+}
+
+// The accumulator along with the instruction that would have run twice, and how many steps it took to get there.
+pub fn solve_with_details(program: &Program) -> Result<(i64, Value), Error> {
+    let mut machine = Machine::new(program.clone());
+    return match machine.run() {
+        Termination::Loop { pc } => Ok((machine.accumulator, json!({ "loop_pc": pc, "steps": machine.steps }))),
+        Termination::Exit => Err(Error::NoLoop),
+        termination => Err(Error::Stopped(termination)),
+    };
+}
+
+pub fn part1(input: &Input) -> Result<i64, Error> {
+    let program = parse_file(input)?;
+    return solve(&program);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let program = parse_file(input)?;
+    return solve_with_details(&program);
+}
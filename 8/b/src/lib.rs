@@ -0,0 +1,56 @@
+use aoc_common::{json, Input, Value};
+use log::{debug, info};
+
+pub use day8vm::vm::{Effect, Instruction, Machine, Opcode, Program, Termination, ACC, JMP, NOP, OPCODES};
+pub use day8vm::debugger::{Breakpoint, Debugger, Executed, Watch};
+pub use day8a::{command, parse_file, Error};
+
+// The instruction with jmp and nop swapped, if it's either of those.
+pub fn swapped(instruction: &Instruction) -> Option<Instruction> {
+    let opcode = match instruction.opcode {
+        opcode if opcode == JMP => NOP,
+        opcode if opcode == NOP => JMP,
+        _ => return None,
+    };
+
+    return Some(Instruction { opcode: opcode, argument: instruction.argument });
+}
+
+// Tries swapping each jmp and nop in turn, and gives the accumulator once the program finishes, along with the
+// instruction that had to be swapped.
+fn fix_program(program: &Program) -> Result<(i64, Value), Error> {
+    let mut machine = Machine::new(program.clone());
+    for i in 0..program.len() {
+        if let Some(swapped) = swapped(&program.instructions[i]) {
+            machine.reset();
+            machine.program.instructions[i] = swapped;
+            debug!("Trying with instruction {} swapped to {}", i, swapped);
+
+            if machine.run() == Termination::Exit {
+                info!("Instruction {} was the corrupted one", i);
+                return Ok((machine.accumulator, json!({ "swapped_pc": i, "instruction": swapped.to_string(), "steps": machine.steps })));
+            }
+            machine.program.instructions[i] = program.instructions[i];
+        }
+    }
+
+    return Err(Error::NoFix);
+}
+
+pub fn solve(program: &Program) -> Result<i64, Error> {
+    return Ok(fix_program(program)?.0);
+}
+
+pub fn solve_with_details(program: &Program) -> Result<(i64, Value), Error> {
+    return fix_program(program);
+}
+
+pub fn part2(input: &Input) -> Result<i64, Error> {
+    let program = parse_file(input)?;
+    return solve(&program);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let program = parse_file(input)?;
+    return solve_with_details(&program);
+}
@@ -0,0 +1,289 @@
+use std::fmt;
+
+use crate::vm::{opcode_names, Instruction, Machine, Program, OPCODES};
+
+// Where `continue` stops: before running the instruction on a line, or before running any instruction of a kind.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Breakpoint {
+    Line(usize),
+    Opcode(&'static str),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Breakpoint::Line(pc) => write!(f, "line {}", pc + 1),
+            Breakpoint::Opcode(name) => write!(f, "every {}", name),
+        };
+    }
+}
+
+// Stops running when the accumulator changes, or when it becomes a value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Watch {
+    Change,
+    Value(i64),
+}
+
+// An instruction that has run, with the accumulator after it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Executed {
+    pub pc: usize,
+    pub instruction: Instruction,
+    pub accumulator: i64,
+}
+
+// How many lines `list` shows either side of the one asked about.
+const LIST_CONTEXT: usize = 5;
+
+// How many instructions `history` shows unless it's told otherwise.
+const DEFAULT_HISTORY: usize = 10;
+
+const HELP: &[&str] = &[
+    "step [count]                Run one instruction, or this many",
+    "continue                    Run until a breakpoint, the watch or the program stops",
+    "run                         Start again from the first instruction, keeping patches, and continue",
+    "break <line|opcode>         Stop before the instruction on this line, or every instruction of this kind",
+    "delete [line|opcode]        Remove a breakpoint, or all of them",
+    "watch [value]               Stop when the accumulator changes, or becomes this value",
+    "unwatch                     Stop watching the accumulator",
+    "history [count]             Show the last instructions that ran",
+    "list [line]                 Show the program around the current line, or this one",
+    "patch <line> <instruction>  Replace the instruction on a line, like \"patch 3 nop +4\"",
+    "info                        Show where the program is, the breakpoints and the watch",
+    "quit                        Stop debugging",
+];
+
+// Runs a program an instruction at a time for someone typing commands. Each command gives back the lines to show them.
+pub struct Debugger {
+    pub machine: Machine,
+    // The program as it was read, before any patches.
+    pub original: Program,
+    pub breakpoints: Vec<Breakpoint>,
+    pub watch: Option<Watch>,
+    // Every instruction that has run since the program last started.
+    pub history: Vec<Executed>,
+    pub quit: bool,
+}
+
+impl Debugger {
+    pub fn new(program: Program) -> Debugger {
+        return Debugger {
+            machine: Machine::new(program.clone()),
+            original: program,
+            breakpoints: Vec::new(),
+            watch: None,
+            history: Vec::new(),
+            quit: false,
+        };
+    }
+
+    pub fn execute(&mut self, line: &str) -> Vec<String> {
+        let mut words = line.split_whitespace();
+        let command = match words.next() {
+            None => return Vec::new(),
+            Some(command) => command,
+        };
+        let argument = words.next();
+        let count = |default: usize| argument.map_or(Ok(default), |count| count.parse::<usize>().map_err(|_| format!("Expected a count, found '{}'", count)));
+
+        let result = match command {
+            "step" | "s" => count(1).map(|count| vec![self.resume(Some(count))]),
+            "continue" | "c" => Ok(vec![self.resume(None)]),
+            "run" | "r" => {
+                self.machine.reset();
+                self.history.clear();
+                Ok(vec![self.resume(None)])
+            },
+            "break" | "b" => self.parse_breakpoint(argument).map(|breakpoint| {
+                let reply = format!("Breakpoint on {}", breakpoint);
+                if !self.breakpoints.contains(&breakpoint) {
+                    self.breakpoints.push(breakpoint);
+                }
+                vec![reply]
+            }),
+            "delete" | "d" => match argument {
+                None => {
+                    self.breakpoints.clear();
+                    Ok(vec![String::from("Removed every breakpoint")])
+                },
+                Some(_) => self.parse_breakpoint(argument).map(|breakpoint| {
+                    self.breakpoints.retain(|existing| *existing != breakpoint);
+                    vec![format!("Removed the breakpoint on {}", breakpoint)]
+                }),
+            },
+            "watch" | "w" => match argument.map(|value| value.parse::<i64>()) {
+                None => {
+                    self.watch = Some(Watch::Change);
+                    Ok(vec![String::from("Watching for the accumulator to change")])
+                },
+                Some(Ok(value)) => {
+                    self.watch = Some(Watch::Value(value));
+                    Ok(vec![format!("Watching for the accumulator to become {}", value)])
+                },
+                Some(Err(_)) => Err(format!("Expected a number to watch for, found '{}'", argument.unwrap_or(""))),
+            },
+            "unwatch" => {
+                self.watch = None;
+                Ok(vec![String::from("Not watching the accumulator")])
+            },
+            "history" | "h" => count(DEFAULT_HISTORY).map(|count| self.show_history(count)),
+            "list" | "l" => match argument {
+                None => Ok(self.list(self.machine.pc)),
+                Some(_) => self.parse_line(argument).map(|pc| self.list(pc)),
+            },
+            "patch" | "p" => {
+                let instruction = line.trim().splitn(3, char::is_whitespace).nth(2).map(|instruction| instruction.trim());
+                self.patch(argument, instruction)
+            },
+            "info" | "i" => Ok(self.info()),
+            "help" | "?" => Ok(HELP.iter().map(|line| String::from(*line)).collect()),
+            "quit" | "q" => {
+                self.quit = true;
+                Ok(Vec::new())
+            },
+            _ => Err(format!("Unknown command '{}', type help for the commands", command)),
+        };
+
+        return match result {
+            Err(why) => vec![why],
+            Ok(lines) => lines
+        };
+    }
+
+    // Where the program is up to, like "line 3: jmp +4 (accumulator 1, 2 steps)".
+    pub fn location(&self) -> String {
+        let state = format!("accumulator {}, {} steps", self.machine.accumulator, self.machine.steps);
+        return match self.machine.program.instructions.get(self.machine.pc) {
+            None => format!("the end of the program ({})", state),
+            Some(instruction) => format!("line {}: {} ({})", self.machine.pc + 1, instruction, state),
+        };
+    }
+
+    fn breakpoint_here(&self) -> Option<&Breakpoint> {
+        let instruction = self.machine.program.instructions.get(self.machine.pc)?;
+        return self.breakpoints.iter().find(|breakpoint| match breakpoint {
+            Breakpoint::Line(pc) => *pc == self.machine.pc,
+            Breakpoint::Opcode(name) => *name == instruction.opcode.name,
+        });
+    }
+
+    // Runs up to `limit` instructions, or until a breakpoint, the watch or the program stops it. A breakpoint on the
+    // first instruction doesn't count, so it's possible to carry on from one.
+    fn resume(&mut self, limit: Option<usize>) -> String {
+        let mut ran = 0;
+        loop {
+            if limit.is_some_and(|limit| ran >= limit) {
+                return format!("At {}", self.location());
+            }
+            if ran > 0 {
+                if let Some(breakpoint) = self.breakpoint_here() {
+                    return format!("Breakpoint on {} at {}", breakpoint, self.location());
+                }
+            }
+
+            let (pc, before) = (self.machine.pc, self.machine.accumulator);
+            if let Some(termination) = self.machine.step() {
+                return format!("Stopped because {}, at {}", termination, self.location());
+            }
+            let after = self.machine.accumulator;
+            self.history.push(Executed { pc: pc, instruction: self.machine.program.instructions[pc], accumulator: after });
+            ran += 1;
+
+            let watched = match self.watch {
+                Some(Watch::Change) => after != before,
+                Some(Watch::Value(value)) => after == value && before != value,
+                None => false,
+            };
+            if watched {
+                return format!("The accumulator went from {} to {} on line {}, now at {}", before, after, pc + 1, self.location());
+            }
+        }
+    }
+
+    // A line number, counting from 1, as an index into the program.
+    fn parse_line(&self, argument: Option<&str>) -> Result<usize, String> {
+        let lines = self.machine.program.len();
+        return match argument.map(|line| line.parse::<usize>()) {
+            Some(Ok(line)) if line >= 1 && line <= lines => Ok(line - 1),
+            _ => Err(format!("Expected a line number from 1 to {}, found '{}'", lines, argument.unwrap_or(""))),
+        };
+    }
+
+    fn parse_breakpoint(&self, argument: Option<&str>) -> Result<Breakpoint, String> {
+        if let Some(opcode) = OPCODES.iter().find(|opcode| Some(opcode.name) == argument) {
+            return Ok(Breakpoint::Opcode(opcode.name));
+        }
+
+        let lines = self.machine.program.len();
+        return self.parse_line(argument)
+            .map(Breakpoint::Line)
+            .map_err(|_| format!("Expected a line number from 1 to {} or one of {}, found '{}'", lines, opcode_names(OPCODES), argument.unwrap_or("")));
+    }
+
+    fn show_history(&self, count: usize) -> Vec<String> {
+        let start = self.history.len().saturating_sub(count);
+        return self.history[start..].iter()
+            .map(|executed| format!("line {}: {} -> accumulator {}", executed.pc + 1, executed.instruction, executed.accumulator))
+            .collect();
+    }
+
+    // The lines around `pc`, marking the current one with "=>", breakpoints with "*" and patches with "~", and saying
+    // how many times each has run.
+    fn list(&self, pc: usize) -> Vec<String> {
+        let program = &self.machine.program;
+        let first = pc.saturating_sub(LIST_CONTEXT);
+        let last = (pc + LIST_CONTEXT).min(program.len().saturating_sub(1));
+        let width = program.len().to_string().len();
+
+        let mut lines = Vec::new();
+        for index in first..=last {
+            let instruction = match program.instructions.get(index) {
+                None => break,
+                Some(instruction) => instruction,
+            };
+            let current = if index == self.machine.pc { "=>" } else { "  " };
+            let breakpoint = if self.breakpoints.contains(&Breakpoint::Line(index)) { "*" } else { " " };
+            let patched = if *instruction != self.original.instructions[index] { "~" } else { " " };
+            lines.push(format!("{}{}{} {:>width$}  {:<8} ran {}", current, breakpoint, patched, index + 1, instruction.to_string(), self.machine.visits[index], width = width));
+        }
+
+        return lines;
+    }
+
+    fn patch(&mut self, line: Option<&str>, instruction: Option<&str>) -> Result<Vec<String>, String> {
+        let pc = self.parse_line(line)?;
+        let instruction = match Program::parse_instruction(pc + 1, instruction.unwrap_or(""), OPCODES) {
+            Err(why) => return Err(why.to_string()),
+            Ok(instruction) => instruction
+        };
+
+        let previous = self.machine.program.instructions[pc];
+        self.machine.program.instructions[pc] = instruction;
+        return Ok(vec![format!("Line {} is now {} (it was {}), type run to start again", pc + 1, instruction, previous)]);
+    }
+
+    fn info(&self) -> Vec<String> {
+        let mut lines = vec![format!("At {}", self.location())];
+        if self.breakpoints.is_empty() {
+            lines.push(String::from("No breakpoints"));
+        } else {
+            lines.push(format!("Breakpoints on {}", self.breakpoints.iter().map(|breakpoint| breakpoint.to_string()).collect::<Vec<String>>().join(", ")));
+        }
+        lines.push(match self.watch {
+            None => String::from("Not watching the accumulator"),
+            Some(Watch::Change) => String::from("Watching for the accumulator to change"),
+            Some(Watch::Value(value)) => format!("Watching for the accumulator to become {}", value),
+        });
+
+        let patched: Vec<String> = (0..self.original.len())
+            .filter(|index| self.machine.program.instructions[*index] != self.original.instructions[*index])
+            .map(|index| format!("line {} ({} -> {})", index + 1, self.original.instructions[index], self.machine.program.instructions[index]))
+            .collect();
+        if !patched.is_empty() {
+            lines.push(format!("Patched {}", patched.join(", ")));
+        }
+
+        return lines;
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt;
+
+use aoc_common::Input;
+use log::trace;
+
+use crate::Error;
+
+// What running an instruction does: how much it adds to the accumulator, and how far it moves the program counter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Effect {
+    pub accumulator: i64,
+    pub jump: i64,
+}
+
+// An instruction the console understands, by name, and what it does given its argument. Adding an instruction only
+// takes a new Opcode in the table given to `Program::parse_with`.
+#[derive(Clone, Copy)]
+pub struct Opcode {
+    pub name: &'static str,
+    pub execute: fn(i32) -> Effect,
+}
+
+impl fmt::Debug for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.name);
+    }
+}
+
+// Opcodes are told apart by name, since their functions can't be compared reliably.
+impl PartialEq for Opcode {
+    fn eq(&self, other: &Opcode) -> bool {
+        return self.name == other.name;
+    }
+}
+
+fn nop(_: i32) -> Effect {
+    return Effect { accumulator: 0, jump: 1 };
+}
+
+fn acc(argument: i32) -> Effect {
+    return Effect { accumulator: argument as i64, jump: 1 };
+}
+
+fn jmp(offset: i32) -> Effect {
+    return Effect { accumulator: 0, jump: offset as i64 };
+}
+
+pub const NOP: Opcode = Opcode { name: "nop", execute: nop };
+pub const ACC: Opcode = Opcode { name: "acc", execute: acc };
+pub const JMP: Opcode = Opcode { name: "jmp", execute: jmp };
+
+// The handheld's instruction set.
+pub const OPCODES: &[Opcode] = &[NOP, ACC, JMP];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub argument: i32,
+}
+
+// Written the way the boot code is, like "jmp -3".
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{} {:+}", self.opcode.name, self.argument);
+    }
+}
+
+// "nop, acc or jmp".
+pub fn opcode_names(opcodes: &[Opcode]) -> String {
+    let names: Vec<&str> = opcodes.iter().map(|opcode| opcode.name).collect();
+    return match names.split_last() {
+        None => String::new(),
+        Some((last, [])) => String::from(*last),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+    };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    // Parses one instruction, using the opcodes in the table.
+    pub fn parse_instruction(line_number: usize, value: &str, opcodes: &[Opcode]) -> Result<Instruction, Error> {
+        let malformed = || Error::Malformed { line: line_number, text: String::from(value), expected: "an instruction and a signed number, like \"acc +1\"" };
+
+        let (name, argument_string) = value.split_once(' ').ok_or_else(malformed)?;
+        let argument = argument_string.parse::<i32>().map_err(|_| malformed())?;
+
+        return match opcodes.iter().find(|opcode| opcode.name == name) {
+            None => Err(Error::UnknownOpcode { line: line_number, text: String::from(value), opcode: String::from(name), expected: opcode_names(opcodes) }),
+            Some(opcode) => Ok(Instruction { opcode: *opcode, argument: argument }),
+        };
+    }
+
+    pub fn parse_with(input: &Input, opcodes: &[Opcode]) -> Result<Program, Error> {
+        let instructions = aoc_common::parse_lines_with(input, |line_number, line| Program::parse_instruction(line_number, line, opcodes))?;
+        return Ok(Program { instructions: instructions });
+    }
+
+    pub fn len(&self) -> usize {
+        return self.instructions.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.instructions.is_empty();
+    }
+}
+
+// Why the machine stopped. Instructions are counted from 0 here, and reported from line 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Termination {
+    // The program counter is just past the last instruction, which is how the boot code is meant to finish.
+    Exit,
+    // The instruction at `pc` has already run once.
+    Loop { pc: usize },
+    // The instruction at `pc` would move the program counter to `target`, which isn't in the program.
+    OutOfBounds { pc: usize, target: i64 },
+    // The machine has run as many instructions as it's allowed to.
+    StepLimit { steps: usize },
+    // The instruction at `pc` would take the accumulator past what an i64 can hold.
+    Overflow { pc: usize },
+}
+
+impl fmt::Display for Termination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Termination::Exit => write!(f, "the program finished"),
+            Termination::Loop { pc } => write!(f, "line {} was about to run a second time", pc + 1),
+            Termination::OutOfBounds { pc, target } => write!(f, "the instruction on line {} goes to line {}, which isn't in the program", pc + 1, target + 1),
+            Termination::StepLimit { steps } => write!(f, "the program ran {} instructions without finishing", steps),
+            Termination::Overflow { pc } => write!(f, "the accumulator overflowed on line {}", pc + 1),
+        };
+    }
+}
+
+// A handheld running a program. It stops before running an instruction a second time, unless `detect_loops` is
+// turned off, and before going past `step_limit` instructions, if there is one.
+#[derive(Debug, Clone)]
+pub struct Machine {
+    pub program: Program,
+    pub pc: usize,
+    pub accumulator: i64,
+    pub steps: usize,
+    // How many times each instruction has run.
+    pub visits: Vec<usize>,
+    pub detect_loops: bool,
+    pub step_limit: Option<usize>,
+}
+
+impl Machine {
+    pub fn new(program: Program) -> Machine {
+        let visits = vec![0; program.len()];
+        return Machine { program: program, pc: 0, accumulator: 0, steps: 0, visits: visits, detect_loops: true, step_limit: None };
+    }
+
+    // Back to the start, keeping the program and settings.
+    pub fn reset(&mut self) {
+        self.pc = 0;
+        self.accumulator = 0;
+        self.steps = 0;
+        self.visits = vec![0; self.program.len()];
+    }
+
+    // Why the machine can't run the next instruction, if it can't.
+    pub fn stopped(&self) -> Option<Termination> {
+        if self.pc == self.program.len() {
+            return Some(Termination::Exit);
+        }
+        if self.detect_loops && self.visits[self.pc] > 0 {
+            return Some(Termination::Loop { pc: self.pc });
+        }
+        if self.step_limit.is_some_and(|limit| self.steps >= limit) {
+            return Some(Termination::StepLimit { steps: self.steps });
+        }
+
+        return None;
+    }
+
+    // Runs the next instruction, or says why it can't and leaves the machine as it is.
+    pub fn step(&mut self) -> Option<Termination> {
+        if let Some(termination) = self.stopped() {
+            return Some(termination);
+        }
+
+        let instruction = self.program.instructions[self.pc];
+        let effect = (instruction.opcode.execute)(instruction.argument);
+        let target = self.pc as i64 + effect.jump;
+        if target < 0 || target > self.program.len() as i64 {
+            return Some(Termination::OutOfBounds { pc: self.pc, target: target });
+        }
+        self.accumulator = match self.accumulator.checked_add(effect.accumulator) {
+            None => return Some(Termination::Overflow { pc: self.pc }),
+            Some(accumulator) => accumulator
+        };
+
+        trace!("{}: {} (accumulator: {})", self.pc, instruction, self.accumulator);
+        self.visits[self.pc] += 1;
+        self.steps += 1;
+        self.pc = target as usize;
+        return None;
+    }
+
+    pub fn run(&mut self) -> Termination {
+        loop {
+            if let Some(termination) = self.step() {
+                return termination;
+            }
+        }
+    }
+}
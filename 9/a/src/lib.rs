@@ -0,0 +1,91 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    NoInvalidNumber(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::NoInvalidNumber(window_size) => write!(f, "Every number is the sum of two of the {} before it", window_size),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+// How many of the previous numbers each number has to be a sum of two of.
+pub const WINDOW_SIZE: usize = 25;
+
+pub fn parse_file(input: &Input) -> Result<Vec<i64>, Error> {
+    return Ok(aoc_common::parse_lines(input)?);
+}
+
+fn check_window(window: &[i64], target_number: i64) -> bool {
+    for i in 0..window.len() {
+        for j in i..window.len() {
+            if window[i] + window[j] == target_number {
+                return true;
+            }
+        }
+    }
+
+    return false;
+}
+
+// The index of the first number that isn't the sum of two in the window before it.
+fn find_first_invalid_number(numbers: &Vec<i64>, window_size: usize) -> Option<usize> {
+    for i in window_size..numbers.len() {
+        let window = &numbers[(i - window_size)..i];
+        let target_number = numbers[i];
+
+        if !check_window(window, target_number) {
+            return Some(i);
+        }
+    }
+
+    return None;
+}
+
+pub fn solve_with_window(numbers: &Vec<i64>, window_size: usize) -> Result<i64, Error> {
+    return Ok(solve_with_window_and_details(numbers, window_size)?.0);
+}
+
+// The invalid number along with where it is in the list.
+pub fn solve_with_window_and_details(numbers: &Vec<i64>, window_size: usize) -> Result<(i64, Value), Error> {
+    let index = find_first_invalid_number(numbers, window_size).ok_or(Error::NoInvalidNumber(window_size))?;
+    return Ok((numbers[index], json!({ "window_size": window_size, "index": index })));
+}
+
+pub fn solve(numbers: &Vec<i64>) -> Result<i64, Error> {
+    return solve_with_window(numbers, WINDOW_SIZE);
+}
+
+pub fn part1(input: &Input) -> Result<i64, Error> {
+    let numbers = parse_file(input)?;
+    return solve(&numbers);
+}
+
+pub fn part1_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let numbers = parse_file(input)?;
+    return solve_with_window_and_details(&numbers, WINDOW_SIZE);
+}
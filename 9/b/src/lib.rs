@@ -0,0 +1,118 @@
+use std::error;
+use std::fmt;
+
+use aoc_common::{json, Input, InputError, Value};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    NoInvalidNumber(usize),
+    NoRegion(i64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Input(why) => write!(f, "{}", why),
+            Error::NoInvalidNumber(window_size) => write!(f, "Every number is the sum of two of the {} before it", window_size),
+            Error::NoRegion(target) => write!(f, "No contiguous run of numbers sums to {}", target),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Input(why) => Some(why),
+            _ => None,
+        };
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(why: InputError) -> Error {
+        return Error::Input(why);
+    }
+}
+
+// How many of the previous numbers each number has to be a sum of two of.
+pub const WINDOW_SIZE: usize = 25;
+
+pub fn parse_file(input: &Input) -> Result<Vec<i64>, Error> {
+    return Ok(aoc_common::parse_lines(input)?);
+}
+
+fn check_window(window: &[i64], target_number: i64) -> bool {
+    for i in 0..window.len() {
+        for j in i..window.len() {
+            if window[i] + window[j] == target_number {
+                return true;
+            }
+        }
+    }
+
+    return false;
+}
+
+// The index of the first number that isn't the sum of two in the window before it.
+fn find_first_invalid_number(numbers: &Vec<i64>, window_size: usize) -> Option<usize> {
+    for i in window_size..numbers.len() {
+        let window = &numbers[(i - window_size)..i];
+        let target_number = numbers[i];
+
+        if !check_window(window, target_number) {
+            return Some(i);
+        }
+    }
+
+    return None;
+}
+
+// The start and end (exclusive) of the first run of numbers that sum to the target.
+fn find_contiguous_region_summing_to(numbers: &Vec<i64>, target: i64) -> Option<(usize, usize)> {
+    for i in 0..numbers.len() - 1 {
+        for j in 1..(numbers.len() - i) {
+            let range = &numbers[i..(i+j)];
+            let sum: i64 = range.iter().sum();
+            if sum == target {
+                return Some((i, i + j));
+            } else if sum > target {
+                break;
+            }
+        }
+    }
+
+    return None;
+}
+
+pub fn solve_with_window(numbers: &Vec<i64>, window_size: usize) -> Result<i64, Error> {
+    return Ok(solve_with_window_and_details(numbers, window_size)?.0);
+}
+
+// The weakness along with the invalid number and the run of numbers that sum to it.
+pub fn solve_with_window_and_details(numbers: &Vec<i64>, window_size: usize) -> Result<(i64, Value), Error> {
+    let index = find_first_invalid_number(numbers, window_size).ok_or(Error::NoInvalidNumber(window_size))?;
+    let first_invalid_number = numbers[index];
+    let (start, end) = find_contiguous_region_summing_to(numbers, first_invalid_number).ok_or(Error::NoRegion(first_invalid_number))?;
+    let summing_region = &numbers[start..end];
+
+    // The region is never empty, so it always has a min and max.
+    let min = summing_region.iter().min().unwrap();
+    let max = summing_region.iter().max().unwrap();
+
+    return Ok((min + max, json!({ "invalid_number": first_invalid_number, "start": start, "end": end, "min": min, "max": max })));
+}
+
+pub fn solve(numbers: &Vec<i64>) -> Result<i64, Error> {
+    return solve_with_window(numbers, WINDOW_SIZE);
+}
+
+pub fn part2(input: &Input) -> Result<i64, Error> {
+    let numbers = parse_file(input)?;
+    return solve(&numbers);
+}
+
+pub fn part2_with_details(input: &Input) -> Result<(i64, Value), Error> {
+    let numbers = parse_file(input)?;
+    return solve_with_window_and_details(&numbers, WINDOW_SIZE);
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_common::Input;
+
+// A phase has regressed (or improved) when its median moves by more than this fraction of the baseline.
+pub const THRESHOLD: f64 = 0.1;
+// Changes smaller than this are timer noise on the quick phases, however large they are relative to the baseline.
+const NOISE_FLOOR: Duration = Duration::from_micros(50);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+pub type Measurement = Result<Timing, Box<dyn Error>>;
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    return durations[durations.len() / 2];
+}
+
+// Times `parse` and `solve` separately over `samples` runs and keeps the median of each, so one slow run doesn't skew it.
+pub fn measure<T, A, E1, E2>(
+    input: &Input,
+    samples: usize,
+    parse: fn(&Input) -> Result<T, E1>,
+    solve: fn(&T) -> Result<A, E2>,
+) -> Measurement
+where
+    E1: Error + 'static,
+    E2: Error + 'static,
+{
+    let mut parse_times = Vec::new();
+    let mut solve_times = Vec::new();
+    for _ in 0..samples.max(1) {
+        let start = Instant::now();
+        let parsed = parse(input)?;
+        parse_times.push(start.elapsed());
+
+        let start = Instant::now();
+        solve(&parsed)?;
+        solve_times.push(start.elapsed());
+    }
+
+    return Ok(Timing { parse: median(parse_times), solve: median(solve_times) });
+}
+
+pub type Baseline = BTreeMap<(usize, usize), Timing>;
+
+// The baseline is a line of "<day> <part> <parse ns> <solve ns>" for each part, with '#' comments.
+pub fn read_baseline(file_name: &Path) -> Result<Baseline, io::Error> {
+    let mut baseline = Baseline::new();
+    for (index, line) in fs::read_to_string(file_name)?.lines().enumerate() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+
+        let values: Vec<u64> = line.split_whitespace().filter_map(|value| value.parse().ok()).collect();
+        if values.len() != 4 {
+            let message = format!("Couldn't parse line {} of {} (\"{}\"): expected <day> <part> <parse ns> <solve ns>", index + 1, file_name.display(), line);
+            return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+        }
+
+        let timing = Timing { parse: Duration::from_nanos(values[2]), solve: Duration::from_nanos(values[3]) };
+        baseline.insert((values[0] as usize, values[1] as usize), timing);
+    }
+
+    return Ok(baseline);
+}
+
+pub fn write_baseline(file_name: &Path, baseline: &Baseline) -> Result<(), io::Error> {
+    let mut contents = String::from("# day part parse_ns solve_ns\n");
+    for ((day, part), timing) in baseline {
+        contents += &format!("{} {} {} {}\n", day, part, timing.parse.as_nanos(), timing.solve.as_nanos());
+    }
+
+    return fs::write(file_name, contents);
+}
+
+fn format_duration(duration: Duration) -> String {
+    return format!("{:.3} ms", duration.as_secs_f64() * 1000.0);
+}
+
+fn format_change(current: Duration, baseline: Option<Duration>) -> (String, i32) {
+    let baseline = match baseline {
+        Some(baseline) if !baseline.is_zero() => baseline,
+        _ => return (String::from("-"), 0),
+    };
+
+    let change = current.as_secs_f64() / baseline.as_secs_f64() - 1.0;
+    let direction = if current.abs_diff(baseline) < NOISE_FLOOR {
+        0
+    } else if change > THRESHOLD {
+        1
+    } else if change < -THRESHOLD {
+        -1
+    } else {
+        0
+    };
+    return (format!("{:+.1}%", change * 100.0), direction);
+}
+
+// Lays out the timings as a table, comparing each phase against the baseline when there is one.
+// Returns the table and whether any phase regressed.
+pub fn report(timings: &Baseline, baseline: Option<&Baseline>) -> (String, bool) {
+    let mut rows = vec![vec![
+        String::from("Day"),
+        String::from("Part"),
+        String::from("Parse"),
+        String::from("Change"),
+        String::from("Solve"),
+        String::from("Change"),
+        String::from(""),
+    ]];
+
+    let mut regressed = false;
+    for ((day, part), timing) in timings {
+        let previous = baseline.and_then(|baseline| baseline.get(&(*day, *part)));
+        let (parse_change, parse_direction) = format_change(timing.parse, previous.map(|previous| previous.parse));
+        let (solve_change, solve_direction) = format_change(timing.solve, previous.map(|previous| previous.solve));
+
+        let status = if parse_direction > 0 || solve_direction > 0 {
+            regressed = true;
+            "regressed"
+        } else if parse_direction < 0 || solve_direction < 0 {
+            "improved"
+        } else {
+            ""
+        };
+
+        rows.push(vec![
+            day.to_string(),
+            part.to_string(),
+            format_duration(timing.parse),
+            parse_change,
+            format_duration(timing.solve),
+            solve_change,
+            String::from(status),
+        ]);
+    }
+
+    let mut widths = vec![0; rows[0].len()];
+    for row in &rows {
+        for (column, cell) in row.iter().enumerate() {
+            widths[column] = widths[column].max(cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    for row in &rows {
+        let cells: Vec<String> = row.iter().enumerate().map(|(column, cell)| format!("{:>width$}", cell, width = widths[column])).collect();
+        table += cells.join("  ").trim_end();
+        table += "\n";
+    }
+
+    return (table, regressed);
+}
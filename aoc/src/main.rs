@@ -0,0 +1,318 @@
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Instant;
+
+use aoc::bench::{self, Baseline};
+use aoc::scaffold;
+use aoc::solutions::{Solution, SOLUTIONS};
+use aoc_common::{json, logging, output, Input};
+
+const USAGE: &str = "Usage:
+    aoc run <day> [part] [input]    Run one day (both parts unless a part other than all is given)
+    aoc run all                     Run every day against its checked in input
+    aoc bench [day] [part]          Time parsing and solving against the checked in inputs (every day by default)
+    aoc new <day> [part]            Create the crates for a new day (both parts unless a part is given) and register them
+
+Parts may be given as 1/2, a/b or all. Without an input, <day>/<a|b>/input is used, and an input file can follow the
+day directly when both parts should run.
+
+Run options:
+    <file>, --input-file <file>    Read the puzzle input from a file
+    -                              Read the puzzle input from standard input
+    --input <text>                 Use <text> as the puzzle input, for puzzles that fit on one line
+    --json                         Print each answer as a line of JSON instead
+
+Bench options:
+    --samples <n>         How many times to run each phase, the median is reported (default 5)
+    --baseline <file>     Where the baseline is kept (default bench_baseline.txt in the workspace)
+    --save-baseline       Store these timings as the new baseline
+
+bench exits with a failure if any phase is slower than the baseline by more than the threshold, or a day fails.
+
+Both take -v or -vv to log what the solvers are doing to stderr, and -q or --quiet to log nothing at all.";
+
+const DEFAULT_SAMPLES: usize = 5;
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    process::exit(1);
+}
+
+fn parse_day(value: &str) -> usize {
+    return match value.parse::<usize>() {
+        Ok(day) if (1..=25).contains(&day) => day,
+        _ => exit_with_usage(&format!("Invalid day '{}', expected a number from 1 to 25", value)),
+    };
+}
+
+fn parse_part(value: &str) -> usize {
+    return match value {
+        "1" | "a" => 1,
+        "2" | "b" => 2,
+        _ => exit_with_usage(&format!("Invalid part '{}', expected 1, 2, a or b", value)),
+    };
+}
+
+// Whether a `run` argument after the day names the part, rather than an input file.
+fn is_part(value: &str) -> bool {
+    return matches!(value, "1" | "2" | "a" | "b" | "all");
+}
+
+fn run(solution: &Solution, input: &Input, json: bool) -> bool {
+    let start = Instant::now();
+    if json {
+        return match (solution.details)(input) {
+            Err(why) => {
+                eprintln!("Day {} part {} failed: {}", solution.day, solution.part, why);
+                false
+            },
+            Ok((answer, mut details)) => {
+                let elapsed = start.elapsed();
+                details["input"] = json!(input.to_string());
+                output::print_json(solution.day, solution.part, &answer, elapsed, details);
+                true
+            },
+        };
+    }
+
+    return match (solution.run)(input) {
+        Err(why) => {
+            eprintln!("Day {} part {} failed: {}", solution.day, solution.part, why);
+            false
+        },
+        Ok(answer) => {
+            println!("Day {} part {}: {}", solution.day, solution.part, answer);
+            true
+        },
+    };
+}
+
+fn select(day: usize, part: Option<usize>) -> Vec<&'static Solution> {
+    let selected: Vec<&Solution> = SOLUTIONS
+        .iter()
+        .filter(|solution| solution.day == day && part.is_none_or(|part| solution.part == part))
+        .collect();
+
+    if selected.is_empty() {
+        match part {
+            Some(part) => eprintln!("There is no solution for day {} part {}", day, part),
+            None => eprintln!("There is no solution for day {}", day),
+        }
+        process::exit(1);
+    }
+
+    return selected;
+}
+
+fn bench(args: &[String]) {
+    let mut samples = DEFAULT_SAMPLES;
+    let mut baseline_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("bench_baseline.txt");
+    let mut save_baseline = false;
+    let mut verbosity = 0;
+    let mut quiet = false;
+    let mut positional = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--samples" => {
+                samples = match args.next().map(|value| value.parse::<usize>()) {
+                    Some(Ok(samples)) if samples > 0 => samples,
+                    _ => exit_with_usage("--samples expects a number greater than 0"),
+                };
+            },
+            "--baseline" => {
+                baseline_file = match args.next() {
+                    Some(file) => PathBuf::from(file),
+                    None => exit_with_usage("--baseline expects a file"),
+                };
+            },
+            "--save-baseline" => save_baseline = true,
+            "-v" | "-vv" => verbosity += arg.len() - 1,
+            "-q" | "--quiet" => quiet = true,
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() > 2 {
+        exit_with_usage("Too many arguments");
+    }
+    logging::init(logging::level_filter(verbosity, quiet));
+
+    let selected: Vec<&Solution> = match positional.first() {
+        None => SOLUTIONS.iter().collect(),
+        Some(day) if *day == "all" => SOLUTIONS.iter().collect(),
+        Some(day) => select(parse_day(day), positional.get(1).map(|part| parse_part(part))),
+    };
+
+    // Without a baseline file the timings are just reported.
+    let baseline = match bench::read_baseline(&baseline_file) {
+        Err(why) if why.kind() == io::ErrorKind::NotFound => None,
+        Err(why) => {
+            eprintln!("Couldn't read the baseline {}: {}", baseline_file.display(), why);
+            process::exit(1);
+        },
+        Ok(baseline) => Some(baseline),
+    };
+
+    let mut timings = Baseline::new();
+    let mut succeeded = true;
+    for solution in selected {
+        match (solution.bench)(&solution.default_input(), samples) {
+            Err(why) => {
+                eprintln!("Day {} part {} failed: {}", solution.day, solution.part, why);
+                succeeded = false;
+            },
+            Ok(timing) => {
+                timings.insert((solution.day, solution.part), timing);
+            },
+        }
+    }
+
+    let (table, regressed) = bench::report(&timings, baseline.as_ref());
+    print!("{}", table);
+    if regressed {
+        println!("\nSome phases are more than {:.0}% slower than the baseline in {}", bench::THRESHOLD * 100.0, baseline_file.display());
+        // Failing lets CI stop on a regression.
+        succeeded = false;
+    }
+
+    if save_baseline {
+        // Only the parts that were just timed are replaced, so benchmarking one day keeps the others.
+        let mut updated = baseline.unwrap_or_default();
+        updated.extend(timings);
+        if let Err(why) = bench::write_baseline(&baseline_file, &updated) {
+            eprintln!("Couldn't write the baseline {}: {}", baseline_file.display(), why);
+            process::exit(1);
+        }
+        println!("Saved the baseline to {}", baseline_file.display());
+    }
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+fn new_day(args: &[String]) {
+    let (day, parts) = match args {
+        [day] => (parse_day(day), vec![1, 2]),
+        [day, part] => (parse_day(day), vec![parse_part(part)]),
+        _ => exit_with_usage("'new' expects a day and optionally a part"),
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    match scaffold::create_day(&root, day, &parts) {
+        Err(why) => {
+            eprintln!("Couldn't create day {}: {}", day, why);
+            process::exit(1);
+        },
+        Ok(created) => {
+            for file_name in created {
+                println!("Created {}", file_name.display());
+            }
+            println!("Registered day {} with the workspace, `aoc run` and aoc/tests/examples.rs", day);
+        },
+    }
+}
+
+fn run_command(args: &[String]) {
+    let mut json = false;
+    let mut verbosity = 0;
+    let mut quiet = false;
+    let mut input = None;
+    let mut positional: Vec<&String> = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let next_input = match arg.as_str() {
+            "--json" => {
+                json = true;
+                continue;
+            },
+            "-v" | "-vv" => {
+                verbosity += arg.len() - 1;
+                continue;
+            },
+            "-q" | "--quiet" => {
+                quiet = true;
+                continue;
+            },
+            "--input-file" => match args.next() {
+                Some(file_name) => Input::File(PathBuf::from(file_name)),
+                None => exit_with_usage("--input-file expects a file"),
+            },
+            "--input" => match args.next() {
+                Some(text) => Input::literal(text),
+                None => exit_with_usage("--input expects the puzzle input"),
+            },
+            // Stdin can only be read once, so it's read up front and shared by both parts.
+            "-" => match Input::stdin() {
+                Err(why) => aoc_common::exit_with_error(why),
+                Ok(input) => input,
+            },
+            _ if arg.starts_with("--") => exit_with_usage(&format!("Unknown option '{}'", arg)),
+            _ if positional.len() == 2 => Input::File(PathBuf::from(arg)),
+            // "aoc run 8 my_input" runs both parts of day 8 against my_input.
+            _ if positional.len() == 1 && *positional[0] != "all" && !is_part(arg) => {
+                if !Path::new(arg).is_file() {
+                    exit_with_usage(&format!("Expected a part (1, 2, a, b or all) or an input file after the day, found '{}'", arg));
+                }
+                Input::File(PathBuf::from(arg))
+            },
+            _ => {
+                positional.push(arg);
+                continue;
+            },
+        };
+
+        if input.is_some() {
+            exit_with_usage("Expected a single input");
+        }
+        input = Some(next_input);
+    }
+
+    logging::init(logging::level_filter(verbosity, quiet));
+
+    let selected = match positional.first() {
+        None => exit_with_usage("Expected a day"),
+        Some(day) if *day == "all" => {
+            if positional.len() > 1 || input.is_some() {
+                exit_with_usage("'run all' doesn't take any more arguments");
+            }
+            SOLUTIONS.iter().collect()
+        },
+        Some(day) => select(parse_day(day), positional.get(1).filter(|part| is_part(part) && **part != "all").map(|part| parse_part(part))),
+    };
+
+    let mut succeeded = true;
+    for solution in selected {
+        succeeded &= match &input {
+            Some(input) => run(solution, input, json),
+            None => run(solution, &solution.default_input(), json),
+        };
+    }
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() >= 2 && args[1] == "bench" {
+        bench(&args[2..]);
+        return;
+    }
+    if args.len() >= 2 && args[1] == "new" {
+        new_day(&args[2..]);
+        return;
+    }
+
+    if args.len() < 2 || args[1] != "run" {
+        exit_with_usage("Expected a command");
+    }
+
+    run_command(&args[2..]);
+}
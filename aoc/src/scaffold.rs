@@ -0,0 +1,289 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn part_letter(part: usize) -> &'static str {
+    return if part == 1 { "a" } else { "b" };
+}
+
+fn crate_name(day: usize, part: usize) -> String {
+    return format!("day{}{}", day, part_letter(part));
+}
+
+fn invalid_data(message: String) -> io::Error {
+    return io::Error::new(io::ErrorKind::InvalidData, message);
+}
+
+fn manifest(day: usize, part: usize) -> String {
+    return format!("[package]
+name = \"{}\"
+version = \"0.1.0\"
+authors = [\"Ben Dunkin <chewygumball@gmail.com>\"]
+edition = \"2018\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc_common = {{ path = \"../../common\" }}
+
+[lints]
+workspace = true
+", crate_name(day, part));
+}
+
+fn library(part: usize) -> String {
+    return format!("use std::error;
+use std::fmt;
+
+use aoc_common::{{json, Input, InputError, Value}};
+
+#[derive(Debug)]
+pub enum Error {{
+    Input(InputError),
+}}
+
+impl fmt::Display for Error {{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{
+        return match self {{
+            Error::Input(why) => write!(f, \"{{}}\", why),
+        }};
+    }}
+}}
+
+impl error::Error for Error {{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {{
+        return match self {{
+            Error::Input(why) => Some(why),
+        }};
+    }}
+}}
+
+impl From<InputError> for Error {{
+    fn from(why: InputError) -> Error {{
+        return Error::Input(why);
+    }}
+}}
+
+pub fn parse_file(input: &Input) -> Result<Vec<String>, Error> {{
+    return Ok(aoc_common::read_lines(input)?);
+}}
+
+pub fn solve(lines: &Vec<String>) -> Result<usize, Error> {{
+    return Ok(lines.len());
+}}
+
+pub fn part{part}(input: &Input) -> Result<usize, Error> {{
+    let lines = parse_file(input)?;
+    return solve(&lines);
+}}
+
+// The answer along with whatever about how it was found is worth printing with --json.
+pub fn part{part}_with_details(input: &Input) -> Result<(usize, Value), Error> {{
+    let lines = parse_file(input)?;
+    return Ok((solve(&lines)?, json!({{ \"lines\": lines.len() }})));
+}}
+", part = part);
+}
+
+fn binary(day: usize, part: usize) -> String {
+    return format!("use std::time::Instant;
+
+use {name}::part{part}_with_details;
+
+fn main() {{
+    let options = aoc_common::cli::parse_args();
+
+    let start = Instant::now();
+    let (answer, mut details) = match part{part}_with_details(&options.input) {{
+        Err(why) => aoc_common::exit_with_error(why),
+        Ok(answer) => answer
+    }};
+
+    if options.json {{
+        details[\"input\"] = aoc_common::json!(options.input.to_string());
+        aoc_common::output::print_json({day}, {part}, &answer, start.elapsed(), details);
+    }} else {{
+        println!(\"Answer: {{}}\", answer);
+    }}
+}}
+", name = crate_name(day, part), day = day, part = part);
+}
+
+fn example_assert(day: usize, part: usize) -> String {
+    return format!("    assert_eq!({}::part{}(&test_input({}, \"{}\")).unwrap(), 0);", crate_name(day, part), part, day, part_letter(part));
+}
+
+fn member_day(line: &str) -> Option<usize> {
+    let (day, _) = line.trim().strip_prefix('"')?.split_once('/')?;
+    return day.parse().ok();
+}
+
+// Adds the parts to the workspace members, which are kept as one line per day in day order.
+fn register_members(text: &str, day: usize, parts: &[usize]) -> Result<String, io::Error> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let start = lines.iter().position(|line| line.trim() == "members = [")
+        .ok_or_else(|| invalid_data(String::from("Couldn't find the workspace members in Cargo.toml")))?;
+    let end = start + lines[start..].iter().position(|line| line.trim() == "]")
+        .ok_or_else(|| invalid_data(String::from("Couldn't find the end of the workspace members in Cargo.toml")))?;
+
+    let mut members: Vec<String> = parts.iter().map(|part| format!("\"{}/{}\"", day, part_letter(*part))).collect();
+    let mut position = end;
+    for index in (start + 1)..end {
+        match member_day(&lines[index]) {
+            Some(member) if member == day => {
+                members.extend(lines[index].split(',').map(|member| member.trim().to_string()).filter(|member| !member.is_empty()));
+                lines.remove(index);
+                position = index;
+                break;
+            },
+            Some(member) if member > day => {
+                position = index;
+                break;
+            },
+            _ => (),
+        }
+    }
+
+    members.sort();
+    lines.insert(position, format!("    {},", members.join(", ")));
+    return Ok(lines.join("\n") + "\n");
+}
+
+// Inserts `new_line` among the lines that `key` recognises, keeping them in order.
+fn insert_ordered(text: &str, new_line: String, new_key: (usize, usize), key: fn(&str) -> Option<(usize, usize)>, file_name: &str) -> Result<String, io::Error> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let keyed: Vec<(usize, (usize, usize))> = lines.iter().enumerate().filter_map(|(index, line)| key(line).map(|k| (index, k))).collect();
+
+    let position = match keyed.iter().rev().find(|(_, k)| *k < new_key) {
+        Some((index, _)) => index + 1,
+        None => match keyed.first() {
+            Some((index, _)) => *index,
+            None => return Err(invalid_data(format!("Couldn't find where to register the new day in {}", file_name))),
+        },
+    };
+
+    lines.insert(position, new_line);
+    return Ok(lines.join("\n") + "\n");
+}
+
+fn dependency_key(line: &str) -> Option<(usize, usize)> {
+    let (name, _) = line.strip_prefix("day")?.split_once(" = ")?;
+    let part = if name.ends_with('a') { 1 } else if name.ends_with('b') { 2 } else { return None };
+    return Some((name[..name.len() - 1].parse().ok()?, part));
+}
+
+fn solution_key(line: &str) -> Option<(usize, usize)> {
+    let rest = line.trim().strip_prefix("Solution { day: ")?;
+    let (day, rest) = rest.split_once(", part: ")?;
+    let (part, _) = rest.split_once(',')?;
+    return Some((day.parse().ok()?, part.parse().ok()?));
+}
+
+fn example_day(line: &str) -> Option<usize> {
+    return line.strip_prefix("fn day")?.strip_suffix("() {")?.parse().ok();
+}
+
+// Each day has one test in examples.rs. A new day gets a test of its own, while a part added to an existing day
+// is asserted alongside the other part.
+fn register_example(text: &str, day: usize, parts: &[usize]) -> Result<String, io::Error> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+
+    if let Some(start) = lines.iter().position(|line| example_day(line) == Some(day)) {
+        let end = start + lines[start..].iter().position(|line| line == "}")
+            .ok_or_else(|| invalid_data(format!("Couldn't find the end of the day {} test in examples.rs", day)))?;
+        // Part 2 goes first so that inserting it doesn't move the start of the test.
+        for part in parts.iter().rev() {
+            let position = if *part == 1 { start + 1 } else { end };
+            lines.insert(position, example_assert(day, *part));
+        }
+        return Ok(lines.join("\n") + "\n");
+    }
+
+    let mut test = vec![
+        String::from("#[test]"),
+        format!("fn day{}() {{", day),
+        String::from("    // Replace the 0s with the answers to the example in question.txt once it's in test_input."),
+    ];
+    test.extend(parts.iter().map(|part| example_assert(day, *part)));
+    test.push(String::from("}"));
+    test.push(String::from(""));
+
+    let position = match lines.iter().position(|line| example_day(line).is_some_and(|other| other > day)) {
+        // Before the #[test] attribute of the next day.
+        Some(index) => index - 1,
+        None => {
+            test.pop();
+            test.insert(0, String::from(""));
+            lines.len()
+        },
+    };
+
+    for (offset, line) in test.into_iter().enumerate() {
+        lines.insert(position + offset, line);
+    }
+    return Ok(lines.join("\n") + "\n");
+}
+
+// Creates the crates for the given parts of `day` under `root`, with a placeholder question.txt, empty inputs, a
+// parse/solve skeleton and a binary, then registers them with the workspace, the runner and the example tests.
+// Nothing is written if any of the parts already exists. Returns the files it created, relative to `root`.
+pub fn create_day(root: &Path, day: usize, parts: &[usize]) -> Result<Vec<PathBuf>, io::Error> {
+    for part in parts {
+        let directory = root.join(day.to_string()).join(part_letter(*part));
+        if directory.exists() {
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", directory.display())));
+        }
+    }
+
+    // Work out every registration before touching anything, so a file that can't be updated leaves the tree as it was.
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let solutions = root.join("aoc").join("src").join("solutions.rs");
+    let examples = root.join("aoc").join("tests").join("examples.rs");
+
+    let mut runner_manifest_text = fs::read_to_string(&runner_manifest)?;
+    let mut solutions_text = fs::read_to_string(&solutions)?;
+    for part in parts {
+        let dependency = format!("{} = {{ path = \"../{}/{}\" }}", crate_name(day, *part), day, part_letter(*part));
+        runner_manifest_text = insert_ordered(&runner_manifest_text, dependency, (day, *part), dependency_key, "aoc/Cargo.toml")?;
+
+        let solution = format!(
+            "    Solution {{ day: {day}, part: {part}, run: |input| Ok({name}::part{part}(input)?.to_string()), details: |input| with_details({name}::part{part}_with_details(input)), bench: |input, samples| bench::measure(input, samples, {name}::parse_file, {name}::solve) }},",
+            day = day, part = part, name = crate_name(day, *part)
+        );
+        solutions_text = insert_ordered(&solutions_text, solution, (day, *part), solution_key, "aoc/src/solutions.rs")?;
+    }
+    let workspace_manifest_text = register_members(&fs::read_to_string(&workspace_manifest)?, day, parts)?;
+    let examples_text = register_example(&fs::read_to_string(&examples)?, day, parts)?;
+
+    let mut created = Vec::new();
+    let question = root.join(day.to_string()).join("question.txt");
+    if !question.exists() {
+        fs::create_dir_all(root.join(day.to_string()))?;
+        fs::write(&question, format!("Day {}\n\nPaste the puzzle text here, including the example and its answer.\n", day))?;
+        created.push(question);
+    }
+
+    for part in parts {
+        let directory = root.join(day.to_string()).join(part_letter(*part));
+        fs::create_dir_all(directory.join("src"))?;
+        let files = [
+            (directory.join("Cargo.toml"), manifest(day, *part)),
+            (directory.join("src").join("lib.rs"), library(*part)),
+            (directory.join("src").join("main.rs"), binary(day, *part)),
+            (directory.join("input"), String::new()),
+            (directory.join("test_input"), String::new()),
+        ];
+        for (file_name, contents) in files {
+            fs::write(&file_name, contents)?;
+            created.push(file_name);
+        }
+    }
+
+    fs::write(&workspace_manifest, workspace_manifest_text)?;
+    fs::write(&runner_manifest, runner_manifest_text)?;
+    fs::write(&solutions, solutions_text)?;
+    fs::write(&examples, examples_text)?;
+
+    return Ok(created.into_iter().map(|file_name| file_name.strip_prefix(root).map(Path::to_path_buf).unwrap_or(file_name)).collect());
+}
@@ -0,0 +1,86 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Input, Value};
+
+use crate::bench::{self, Measurement};
+
+// An answer along with what the day can say about how it got there, for `aoc run --json`.
+pub type Details = Result<(String, Value), Box<dyn Error>>;
+
+pub struct Solution {
+    pub day: usize,
+    pub part: usize,
+    pub run: fn(&Input) -> Result<String, Box<dyn Error>>,
+    pub details: fn(&Input) -> Details,
+    pub bench: fn(&Input, usize) -> Measurement,
+}
+
+impl Solution {
+    pub fn directory(&self) -> PathBuf {
+        let part = if self.part == 1 { "a" } else { "b" };
+        return Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(self.day.to_string()).join(part);
+    }
+
+    pub fn default_input(&self) -> Input {
+        return Input::File(self.directory().join("input"));
+    }
+}
+
+fn with_details<A: Display, E: Error + 'static>(result: Result<(A, Value), E>) -> Details {
+    let (answer, details) = result?;
+    return Ok((answer.to_string(), details));
+}
+
+pub static SOLUTIONS: &[Solution] = &[
+    Solution { day: 1, part: 1, run: |input| Ok(day1a::part1(input)?.to_string()), details: |input| with_details(day1a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day1a::parse_file, day1a::solve) },
+    Solution { day: 1, part: 2, run: |input| Ok(day1b::part2(input)?.to_string()), details: |input| with_details(day1b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day1b::parse_file, day1b::solve) },
+    Solution { day: 2, part: 1, run: |input| Ok(day2a::part1(input)?.to_string()), details: |input| with_details(day2a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day2a::parse_file, day2a::solve) },
+    Solution { day: 2, part: 2, run: |input| Ok(day2b::part2(input)?.to_string()), details: |input| with_details(day2b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day2b::parse_file, day2b::solve) },
+    Solution { day: 3, part: 1, run: |input| Ok(day3a::part1(input)?.to_string()), details: |input| with_details(day3a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day3a::parse_file, day3a::solve) },
+    Solution { day: 3, part: 2, run: |input| Ok(day3b::part2(input)?.to_string()), details: |input| with_details(day3b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day3b::parse_file, day3b::solve) },
+    Solution { day: 4, part: 1, run: |input| Ok(day4a::part1(input)?.to_string()), details: |input| with_details(day4a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day4a::parse_file, day4a::solve) },
+    Solution { day: 4, part: 2, run: |input| Ok(day4b::part2(input)?.to_string()), details: |input| with_details(day4b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day4b::parse_file, day4b::solve) },
+    Solution { day: 5, part: 1, run: |input| Ok(day5a::part1(input)?.to_string()), details: |input| with_details(day5a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day5a::parse_file, day5a::solve) },
+    Solution { day: 5, part: 2, run: |input| Ok(day5b::part2(input)?.to_string()), details: |input| with_details(day5b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day5b::parse_file, day5b::solve) },
+    Solution { day: 6, part: 1, run: |input| Ok(day6a::part1(input)?.to_string()), details: |input| with_details(day6a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day6a::parse_file, day6a::solve) },
+    Solution { day: 6, part: 2, run: |input| Ok(day6b::part2(input)?.to_string()), details: |input| with_details(day6b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day6b::parse_file, day6b::solve) },
+    Solution { day: 7, part: 1, run: |input| Ok(day7a::part1(input)?.to_string()), details: |input| with_details(day7a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day7a::parse_file, day7a::solve) },
+    Solution { day: 7, part: 2, run: |input| Ok(day7b::part2(input)?.to_string()), details: |input| with_details(day7b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day7b::parse_file, day7b::solve) },
+    Solution { day: 8, part: 1, run: |input| Ok(day8a::part1(input)?.to_string()), details: |input| with_details(day8a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day8a::parse_file, day8a::solve) },
+    Solution { day: 8, part: 2, run: |input| Ok(day8b::part2(input)?.to_string()), details: |input| with_details(day8b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day8b::parse_file, day8b::solve) },
+    Solution { day: 9, part: 1, run: |input| Ok(day9a::part1(input)?.to_string()), details: |input| with_details(day9a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day9a::parse_file, day9a::solve) },
+    Solution { day: 9, part: 2, run: |input| Ok(day9b::part2(input)?.to_string()), details: |input| with_details(day9b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day9b::parse_file, day9b::solve) },
+    Solution { day: 10, part: 1, run: |input| Ok(day10a::part1(input)?.to_string()), details: |input| with_details(day10a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day10a::parse_file, day10a::solve) },
+    Solution { day: 10, part: 2, run: |input| Ok(day10b::part2(input)?.to_string()), details: |input| with_details(day10b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day10b::parse_file, day10b::solve) },
+    Solution { day: 11, part: 1, run: |input| Ok(day11a::part1(input)?.to_string()), details: |input| with_details(day11a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day11a::parse_file, day11a::solve) },
+    Solution { day: 11, part: 2, run: |input| Ok(day11b::part2(input)?.to_string()), details: |input| with_details(day11b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day11b::parse_file, day11b::solve) },
+    Solution { day: 12, part: 1, run: |input| Ok(day12a::part1(input)?.to_string()), details: |input| with_details(day12a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day12a::parse_file, day12a::solve) },
+    Solution { day: 12, part: 2, run: |input| Ok(day12b::part2(input)?.to_string()), details: |input| with_details(day12b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day12b::parse_file, day12b::solve) },
+    Solution { day: 13, part: 1, run: |input| Ok(day13a::part1(input)?.to_string()), details: |input| with_details(day13a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day13a::parse_file, day13a::solve) },
+    Solution { day: 13, part: 2, run: |input| Ok(day13b::part2(input)?.to_string()), details: |input| with_details(day13b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day13b::parse_file, day13b::solve) },
+    Solution { day: 14, part: 1, run: |input| Ok(day14a::part1(input)?.to_string()), details: |input| with_details(day14a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day14a::parse_file, day14a::solve) },
+    Solution { day: 14, part: 2, run: |input| Ok(day14b::part2(input)?.to_string()), details: |input| with_details(day14b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day14b::parse_file, day14b::solve) },
+    Solution { day: 15, part: 1, run: |input| Ok(day15a::part1(input)?.to_string()), details: |input| with_details(day15a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day15a::parse_file, day15a::solve) },
+    Solution { day: 15, part: 2, run: |input| Ok(day15b::part2(input)?.to_string()), details: |input| with_details(day15b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day15b::parse_file, day15b::solve) },
+    Solution { day: 16, part: 1, run: |input| Ok(day16a::part1(input)?.to_string()), details: |input| with_details(day16a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day16a::parse_file, day16a::solve) },
+    Solution { day: 16, part: 2, run: |input| Ok(day16b::part2(input)?.to_string()), details: |input| with_details(day16b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day16b::parse_file, day16b::solve) },
+    Solution { day: 17, part: 1, run: |input| Ok(day17a::part1(input)?.to_string()), details: |input| with_details(day17a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day17a::parse_file, day17a::solve) },
+    Solution { day: 17, part: 2, run: |input| Ok(day17b::part2(input)?.to_string()), details: |input| with_details(day17b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day17b::parse_file, day17b::solve) },
+    Solution { day: 18, part: 1, run: |input| Ok(day18a::part1(input)?.to_string()), details: |input| with_details(day18a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day18a::parse_file, day18a::solve) },
+    Solution { day: 18, part: 2, run: |input| Ok(day18b::part2(input)?.to_string()), details: |input| with_details(day18b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day18b::parse_file, day18b::solve) },
+    Solution { day: 19, part: 1, run: |input| Ok(day19a::part1(input)?.to_string()), details: |input| with_details(day19a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day19a::parse_file, day19a::solve) },
+    Solution { day: 19, part: 2, run: |input| Ok(day19b::part2(input)?.to_string()), details: |input| with_details(day19b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day19b::parse_file, day19b::solve) },
+    Solution { day: 20, part: 1, run: |input| Ok(day20a::part1(input)?.to_string()), details: |input| with_details(day20a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day20a::parse_file, day20a::solve) },
+    Solution { day: 20, part: 2, run: |input| Ok(day20b::part2(input)?.to_string()), details: |input| with_details(day20b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day20b::parse_file, day20b::solve) },
+    Solution { day: 21, part: 1, run: |input| Ok(day21a::part1(input)?.to_string()), details: |input| with_details(day21a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day21a::parse_file, day21a::solve) },
+    Solution { day: 21, part: 2, run: |input| Ok(day21b::part2(input)?.to_string()), details: |input| with_details(day21b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day21b::parse_file, day21b::solve) },
+    Solution { day: 22, part: 1, run: |input| Ok(day22a::part1(input)?.to_string()), details: |input| with_details(day22a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day22a::parse_file, day22a::solve) },
+    Solution { day: 22, part: 2, run: |input| Ok(day22b::part2(input)?.to_string()), details: |input| with_details(day22b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day22b::parse_file, day22b::solve) },
+    Solution { day: 23, part: 1, run: |input| Ok(day23a::part1(input)?.to_string()), details: |input| with_details(day23a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day23a::parse_file, day23a::solve) },
+    Solution { day: 23, part: 2, run: |input| Ok(day23b::part2(input)?.to_string()), details: |input| with_details(day23b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day23b::parse_file, day23b::solve) },
+    Solution { day: 24, part: 1, run: |input| Ok(day24a::part1(input)?.to_string()), details: |input| with_details(day24a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day24a::parse_file, day24a::solve) },
+    Solution { day: 24, part: 2, run: |input| Ok(day24b::part2(input)?.to_string()), details: |input| with_details(day24b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day24b::parse_file, day24b::solve) },
+    Solution { day: 25, part: 1, run: |input| Ok(day25a::part1(input)?.to_string()), details: |input| with_details(day25a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day25a::parse_file, day25a::solve) },
+];
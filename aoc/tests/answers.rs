@@ -0,0 +1,59 @@
+// Checks every part against its real `input`, using the answers recorded in answers.txt at the workspace root.
+// The file is optional and is in the same "Day <day> part <part>: <answer>" form that `aoc run` prints, so it can be
+// regenerated with `aoc run all | grep '^Day' > answers.txt` once the answers have been accepted.
+
+use std::fs;
+use std::path::Path;
+
+use aoc::solutions::SOLUTIONS;
+
+fn parse_answer(line: &str) -> Option<(usize, usize, &str)> {
+    let (name, answer) = line.split_once(": ")?;
+    let mut words = name.split(' ');
+    if words.next() != Some("Day") {
+        return None;
+    }
+    let day = words.next()?.parse().ok()?;
+    if words.next() != Some("part") {
+        return None;
+    }
+    let part = words.next()?.parse().ok()?;
+
+    return Some((day, part, answer));
+}
+
+#[test]
+fn real_inputs() {
+    let answers_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("answers.txt");
+    let answers = match fs::read_to_string(&answers_file) {
+        Err(_) => {
+            eprintln!("No answers at {}, skipping", answers_file.display());
+            return;
+        },
+        Ok(answers) => answers
+    };
+
+    let mut failures = Vec::new();
+    for (index, line) in answers.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (day, part, expected) = match parse_answer(line) {
+            None => panic!("Couldn't parse line {} of {} (\"{}\")", index + 1, answers_file.display(), line),
+            Some(answer) => answer
+        };
+        let solution = match SOLUTIONS.iter().find(|solution| solution.day == day && solution.part == part) {
+            None => panic!("There is no solution for day {} part {}", day, part),
+            Some(solution) => solution
+        };
+
+        match (solution.run)(&solution.default_input()) {
+            Err(why) => failures.push(format!("Day {} part {} failed: {}", day, part, why)),
+            Ok(answer) if answer != expected => failures.push(format!("Day {} part {}: expected {}, got {}", day, part, expected, answer)),
+            Ok(_) => {},
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,775 @@
+// Runs every part against its `test_input` and checks the answer given for the example in question.txt.
+
+use std::path::Path;
+
+use aoc_common::{json, Input};
+
+fn test_input(day: usize, part: &str) -> Input {
+    return Input::File(Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(day.to_string()).join(part).join("test_input"));
+}
+
+#[test]
+fn day1() {
+    assert_eq!(day1a::part1(&test_input(1, "a")).unwrap(), 514579);
+    assert_eq!(day1b::part2(&test_input(1, "b")).unwrap(), 241861950);
+}
+
+#[test]
+fn day1_k_sum() {
+    let report = day1b::parse_file(&test_input(1, "b")).unwrap();
+    assert_eq!(day1b::k_sum(&report, 2, 2020), vec![vec![0, 3]]);
+    assert_eq!(day1b::k_sum(&report, 3, 2020), vec![vec![1, 2, 4]]);
+    assert_eq!(day1b::product_of_sum(&report, 2, 2020).unwrap(), 514579);
+
+    // Equal entries are still different entries, but one entry can't be used twice.
+    assert_eq!(day1b::k_sum(&vec![1010, 5, 1010, 1010], 2, 2020), vec![vec![0, 2], vec![0, 3], vec![2, 3]]);
+    assert_eq!(day1b::k_sum(&vec![1010, 5], 2, 2020), Vec::<Vec<usize>>::new());
+    assert_eq!(day1b::k_sum(&vec![-5, 10, 5, 0, 15], 4, 25), vec![vec![0, 1, 2, 4]]);
+
+    // Sums past the ends of an i64 are out of reach rather than wrapping around.
+    assert_eq!(day1a::k_sum(&vec![i64::MAX, 1, -2, i64::MIN], 2, -1), vec![vec![0, 3], vec![1, 2]]);
+    assert_eq!(day1a::k_sum(&vec![i64::MAX, 5, i64::MAX, 1], 3, 6), Vec::<Vec<usize>>::new());
+    assert!(matches!(day1a::product_of_sum(&vec![i64::MAX / 2, 0, i64::MAX / 2 + 1], 2, i64::MAX), Err(day1a::Error::TooLarge { k: 2, .. })));
+}
+
+#[test]
+fn day2() {
+    assert_eq!(day2a::part1(&test_input(2, "a")).unwrap(), 2);
+    assert_eq!(day2b::part2(&test_input(2, "b")).unwrap(), 1);
+}
+
+#[test]
+fn day2_policies() {
+    use day2a::policy::{Interpretation, Violation};
+
+    let passwords = day2a::parse_file_with(&Input::literal("1-3 a; forbid xyz; distinct 4: abcde
+positions 1-3 b; regex ^c+$: cbcb
+2-9 c; distinct 3: ccccccccc"), Interpretation::Positions).unwrap();
+    let violations: Vec<Vec<Violation>> = passwords.iter().map(|password| password.violations()).collect();
+
+    assert!(violations[0].is_empty());
+    assert_eq!(violations[1], vec![
+        Violation { clause: String::from("positions 1-3 b"), reason: String::from("'b' is at neither position 1 nor 3") },
+        Violation { clause: String::from("regex ^c+$"), reason: String::from("doesn't match") },
+    ]);
+    assert_eq!(violations[2], vec![
+        Violation { clause: String::from("positions 2-9 c"), reason: String::from("'c' is at both positions 2 and 9") },
+        Violation { clause: String::from("distinct 3"), reason: String::from("has 1 different characters, expected at least 3") },
+    ]);
+    assert_eq!(passwords[0].policy(), "positions 1-3 a; forbid xyz; distinct 4");
+    let rejected = day2a::rejections(&passwords);
+    assert_eq!(rejected.iter().map(|rejection| rejection.password.line).collect::<Vec<usize>>(), vec![2, 3]);
+    assert_eq!(rejected[0].violations, violations[1]);
+
+    let count = day2a::parse_file(&Input::literal("1-3 a; forbid e: abcde")).unwrap();
+    assert_eq!(count[0].violations(), vec![Violation { clause: String::from("forbid e"), reason: String::from("contains 'e'") }]);
+    assert!(day2a::parse_file(&Input::literal("1-3 a; distinct many: abcde")).is_err());
+    assert!(day2b::parse_file(&Input::literal("0-3 a: abcde")).is_err());
+
+    // A regex has to match the whole password, and can't hold the separators of the policy line.
+    let regex = day2a::parse_file(&Input::literal("regex c|bc: abc\nregex a.c: abc")).unwrap();
+    assert!(!regex[0].is_valid());
+    assert!(regex[1].is_valid());
+    assert!(matches!(day2a::parse_file(&Input::literal("regex a: b: ab")), Err(day2a::Error::Malformed { line: 1, .. })));
+}
+
+#[test]
+fn day2_audit() {
+    let audit = day2a::audit::audit_file(&test_input(2, "a")).unwrap();
+    assert_eq!(audit.passwords, 3);
+    assert_eq!(audit.count.valid, 2);
+    assert_eq!(audit.positions.valid, 1);
+    assert_eq!(audit.count.failures_by_letter.get(&'b'), Some(&1));
+    assert_eq!(audit.count.out_of_range.get(&1), Some(&1));
+    assert_eq!(audit.positions.failures_by_letter.get(&'c'), Some(&1));
+    assert_eq!(audit.only_valid_by_count, vec![(3, String::from("ccccccccc"))]);
+    assert!(audit.only_valid_by_positions.is_empty());
+
+    let csv = day2a::audit::audit_file(&Input::literal("1-3 a: a,a")).unwrap().to_csv();
+    assert!(csv.ends_with("only_valid_by,count,1,\"a,a\"\n"));
+
+    // There's no position 0, so the second line only reads as a count.
+    let audit = day2a::audit::audit_file(&Input::literal("1-3 a: abc\n0-3 a: aaaa")).unwrap();
+    assert_eq!(audit.count.valid, 1);
+    assert_eq!(audit.positions.valid, 1);
+    assert_eq!(audit.positions.unreadable.iter().map(|(line, _)| *line).collect::<Vec<usize>>(), vec![2]);
+    assert!(audit.count.unreadable.is_empty());
+}
+
+#[test]
+fn day3() {
+    assert_eq!(day3a::part1(&test_input(3, "a")).unwrap(), 7);
+    assert_eq!(day3b::part2(&test_input(3, "b")).unwrap(), 336);
+}
+
+#[test]
+fn day3_slopes() {
+    use day3a::{Goal, Slope};
+
+    let map = day3a::parse_file(&test_input(3, "a")).unwrap();
+    assert_eq!(day3a::product_of_trees(&map, &day3b::SLOPES).unwrap(), 336);
+
+    // Going left wraps around to the right hand side of the map.
+    let left = day3a::run(&map, Slope { dx: -1, dy: 1 }).unwrap();
+    assert_eq!(left.collisions, vec![(9, 2), (8, 3), (5, 6), (3, 8), (1, 10)]);
+    assert_eq!(day3a::run(&map, Slope { dx: -3, dy: 1 }).unwrap().collisions.len(), day3a::run(&map, Slope { dx: 8, dy: 1 }).unwrap().collisions.len());
+    assert!(day3a::run(&map, Slope { dx: 1, dy: 0 }).is_err());
+
+    let slopes = day3a::every_slope(7, 2).unwrap();
+    assert_eq!(slopes.len(), 30);
+    let runs = day3a::run_all(&map, &slopes).unwrap();
+    assert_eq!(day3a::best_run(&runs, Goal::MostTrees).unwrap().slope, Slope { dx: 3, dy: 1 });
+    assert_eq!(day3a::best_run(&runs, Goal::FewestTrees).unwrap().collisions.len(), 0);
+
+    assert_eq!(day3a::parse_slope("-2, 3"), Some(Slope { dx: -2, dy: 3 }));
+    assert_eq!(day3a::parse_slope("2"), None);
+
+    // Huge steps go round the map rather than overflowing, and bounds with too many slopes to try are turned down.
+    let wide = day3a::run(&map, Slope { dx: i64::MAX, dy: 1 }).unwrap();
+    assert_eq!(wide.collisions, day3a::run(&map, Slope { dx: i64::MAX % 11, dy: 1 }).unwrap().collisions);
+    assert_eq!(day3a::run(&map, Slope { dx: i64::MIN, dy: usize::MAX }).unwrap().collisions.len(), 0);
+    assert!(matches!(day3a::every_slope(usize::MAX, 1), Err(day3a::Error::TooManySlopes { .. })));
+    assert!(matches!(day3a::every_slope(1, usize::MAX), Err(day3a::Error::TooManySlopes { .. })));
+    assert!(matches!(day3a::render::render(&map, Slope { dx: i64::MAX, dy: 1 }), Err(day3a::Error::TooWide(_))));
+}
+
+#[test]
+fn day3_render() {
+    use day3a::render::{encode, image_format, render, ImageFormat, PIXELS_PER_SQUARE};
+    use day3a::Slope;
+
+    // The path in question.txt, which repeats the map further than the path goes.
+    let expected = [
+        "..##.........##.........##...",
+        "#..O#...#..#...#...#..#...#..",
+        ".#....X..#..#....#..#..#....#",
+        "..#.#...#O#..#.#...#.#..#.#..",
+        ".#...##..#..X...##..#..#...##",
+        "..#.##.......#.X#.......#.##.",
+        ".#.#.#....#.#.#.#.O..#.#.#.#.",
+        ".#........#.#........X.#.....",
+        "#.##...#...#.##...#...#.X#...",
+        "#...##....##...##....##...#X.",
+        ".#..#...#.#.#..#...#.#.#..#..",
+    ];
+    let map = day3a::parse_file(&test_input(3, "a")).unwrap();
+    let rows = render(&map, day3a::SLOPE).unwrap();
+    assert_eq!(rows.len(), 11);
+    for (row, expected) in rows.iter().zip(expected.iter()) {
+        assert_eq!(row.len(), 33);
+        assert!(row.starts_with(expected));
+    }
+    assert_eq!(rows.iter().map(|row| row.matches('X').count()).sum::<usize>(), 7);
+
+    // Going left puts the copies of the map the path needs before the first one.
+    let left = render(&map, Slope { dx: -3, dy: 2 }).unwrap();
+    assert_eq!(left[0].len(), 33);
+    assert_eq!(left[2].chars().nth(19), Some('O'));
+    assert_eq!(left[10].chars().nth(7), Some('O'));
+    assert_eq!(left[1].matches(&['O', 'X'][..]).count(), 0);
+
+    assert_eq!(image_format(std::path::Path::new("path.PGM")), Some(ImageFormat::Pgm));
+    assert_eq!(image_format(std::path::Path::new("path.png")), None);
+    let image = encode(&rows, ImageFormat::Ppm);
+    let header = format!("P6\n{} {}\n255\n", 33 * PIXELS_PER_SQUARE, 11 * PIXELS_PER_SQUARE);
+    assert!(image.starts_with(header.as_bytes()));
+    assert_eq!(image.len(), header.len() + 33 * 11 * PIXELS_PER_SQUARE * PIXELS_PER_SQUARE * 3);
+    assert_eq!(encode(&rows, ImageFormat::Pgm).len(), header.len() + 33 * 11 * PIXELS_PER_SQUARE * PIXELS_PER_SQUARE);
+}
+
+#[test]
+fn day4() {
+    assert_eq!(day4a::part1(&test_input(4, "a")).unwrap(), 2);
+    assert_eq!(day4b::part2(&test_input(4, "b")).unwrap(), 4);
+}
+
+#[test]
+fn day4_schema() {
+    use day4a::schema::{parse_schema, FieldType};
+
+    let passports = day4a::parse_file(&test_input(4, "b")).unwrap();
+    assert_eq!(day4a::count_valid(&passports, &day4a::PRESENCE_SCHEMA), 8);
+    assert_eq!(day4a::count_valid(&passports, &day4b::PASSPORT_SCHEMA), 4);
+    assert_eq!(day4b::PASSPORT_SCHEMA.rule("hgt").unwrap().field_type.to_string(), "unit 150-193cm 59-76in");
+
+    let visa = parse_schema("the visa schema", "# Visas\n\nvid required regex ^V[0-9]+$\ndst required enum npl ice\nstay optional unit 1-30d 1-4w\n").unwrap();
+    assert_eq!(visa.keys(), vec!["vid", "dst", "stay"]);
+    let visas = day4a::parse_file(&Input::literal("vid:V12 dst:npl\n\nvid:V3 dst:npl stay:5w\n\ndst:ice vid:V4\nstay:2w\n\nvid:W5 dst:ice")).unwrap();
+    assert_eq!(day4a::count_valid(&visas, &visa), 2);
+    assert_eq!(visas[2].line, 5);
+    assert_eq!(visas[2].value("stay"), Some("2w"));
+    assert_eq!(day4a::count_valid(&visas, &day4a::PRESENCE_SCHEMA), 0);
+
+    let stay = &visa.rule("stay").unwrap().field_type;
+    assert_eq!(stay.violation("5w"), Some(String::from("5w isn't between 1w and 4w")));
+    assert_eq!(stay.violation("5y"), Some(String::from("doesn't end in one of d or w")));
+    assert!(matches!(visa.rule("vid").unwrap().field_type, FieldType::Regex { .. }));
+
+    // A regex has to match the whole value, anchored or not.
+    let id = parse_schema("an ID schema", "pid required regex [0-9]{9}").unwrap();
+    let pid = &id.rule("pid").unwrap().field_type;
+    assert_eq!(pid.violation("123456789"), None);
+    assert_eq!(pid.violation("1234567890"), Some(String::from("doesn't match [0-9]{9}")));
+    assert_eq!(pid.to_string(), "regex [0-9]{9}");
+
+    assert!(parse_schema("a schema", "vid mandatory").is_err());
+    assert!(parse_schema("a schema", "vid required int 5-1").is_err());
+    assert!(parse_schema("a schema", "vid required colour").is_err());
+    assert!(parse_schema("a schema", "vid required\nvid optional").is_err());
+}
+
+#[test]
+fn day4_report() {
+    use day4a::report::{report_all, InvalidField};
+    use day4a::Field;
+
+    let passports = day4a::parse_file(&test_input(4, "b")).unwrap();
+    let reports = report_all(&day4b::PASSPORT_SCHEMA, &passports);
+    assert_eq!(reports.iter().filter(|report| report.is_valid()).count(), 4);
+    assert_eq!((reports[0].record, reports[0].first_line, reports[0].last_line), (1, 1, 2));
+    assert!(reports[4].missing.is_empty());
+    assert_eq!(reports[4].invalid, vec![
+        InvalidField { field: Field { key: String::from("eyr"), value: String::from("1972"), line: 14 }, reason: String::from("1972 isn't between 2020 and 2030") },
+        InvalidField { field: Field { key: String::from("hgt"), value: String::from("170"), line: 15 }, reason: String::from("doesn't end in one of cm or in") },
+        InvalidField { field: Field { key: String::from("pid"), value: String::from("186cm"), line: 15 }, reason: String::from("doesn't match ^[0-9]{9}$") },
+    ]);
+
+    let passports = day4a::parse_file(&Input::literal("byr:1990 ecl:blu\nfoo:bar ecl:grn\n\nbyr:1980 iyr:2010 eyr:2020 hgt:150cm hcl:#000000 ecl:oth pid:000000001\n\nbyr:1980 iyr:2010 eyr:2020 hgt:150cm hcl:#000000 ecl:oth pid:000000001 pid:123\n\nbyr:1980 iyr:2010 eyr:2020 hgt:150cm hcl:#000000 ecl:oth pid:000000001 foo:bar")).unwrap();
+    let reports = report_all(&day4b::PASSPORT_SCHEMA, &passports);
+    assert_eq!(reports[0].missing, vec!["iyr", "eyr", "hgt", "hcl", "pid"]);
+    assert_eq!(reports[0].unknown, vec![Field { key: String::from("foo"), value: String::from("bar"), line: 2 }]);
+    assert_eq!(reports[0].duplicates, vec![Field { key: String::from("ecl"), value: String::from("grn"), line: 2 }]);
+    assert_eq!(reports[0].to_string(), "Passport 1 (lines 1-2): missing iyr, eyr, hgt, hcl, pid; warnings: unknown foo \"bar\" on line 2, duplicate ecl \"grn\" on line 2");
+    assert_eq!(reports[1].to_string(), "Passport 2 (lines 4-4): valid");
+
+    // Unknown and duplicated fields are only warnings, but a duplicate still has to hold a valid value.
+    assert!(reports[2].has_warnings());
+    assert!(!reports[2].is_valid());
+    assert_eq!(reports[2].invalid.len(), 1);
+    assert!(reports[3].is_valid());
+    assert_eq!(reports[3].to_string(), "Passport 4 (lines 8-8): valid; warnings: unknown foo \"bar\" on line 8");
+
+    let json = reports[0].to_json();
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["warnings"]["unknown"][0]["key"], "foo");
+    assert_eq!(json["missing"].as_array().unwrap().len(), 5);
+}
+
+#[test]
+fn day4_convert() {
+    use day4a::convert::{read, to_batch, write, Format};
+
+    let batch = "ecl:gry pid:860033327 eyr:2020\nhcl:#fffffd\nbyr:1937 zip:a,\"b\" ecl:amb\n\niyr:2013\n";
+    let passports = read(&Input::literal(batch), Format::Batch).unwrap();
+    let keys = |passports: &Vec<day4a::Passport>| passports.iter().map(|passport| passport.fields.iter().map(|field| format!("{}:{}", field.key, field.value)).collect::<Vec<String>>()).collect::<Vec<Vec<String>>>();
+
+    let csv = write(&passports, Format::Csv, 80);
+    assert!(csv.starts_with("record,key,value\n1,ecl,gry\n"));
+    assert!(csv.ends_with("\n1,zip,\"a,\"\"b\"\"\"\n1,ecl,amb\n2,iyr,2013\n"));
+    let json = write(&passports, Format::JsonLines, 80);
+    assert_eq!(json.lines().nth(1), Some("[[\"iyr\",\"2013\"]]"));
+
+    for (text, format) in &[(csv, Format::Csv), (json, Format::JsonLines)] {
+        let converted = read(&Input::literal(text), *format).unwrap();
+        assert_eq!(keys(&converted), keys(&passports));
+        assert_eq!(write(&converted, Format::Batch, 30), to_batch(&passports, 30));
+    }
+
+    assert_eq!(to_batch(&passports, 30), "ecl:gry pid:860033327 eyr:2020\nhcl:#fffffd byr:1937 zip:a,\"b\"\necl:amb\n\niyr:2013\n");
+    assert_eq!(to_batch(&passports, 1).lines().count(), 9);
+    assert_eq!(read(&Input::literal(&to_batch(&passports, 200)), Format::Batch).unwrap()[0].last_line, 1);
+
+    assert!(read(&Input::literal("key,value\n"), Format::Csv).is_err());
+    assert!(read(&Input::literal("record,key,value\n1,ecl,light blue\n"), Format::Csv).is_err());
+    assert!(read(&Input::literal("record,key,value\n1,\"ecl,gry\n"), Format::Csv).is_err());
+    assert!(read(&Input::literal("{\"ecl\": \"gry\"}"), Format::JsonLines).is_err());
+    assert!(read(&Input::literal("[]"), Format::JsonLines).is_err());
+}
+
+#[test]
+fn day5() {
+    // Part 2 has no example, it depends on the gaps in a full plane of boarding passes.
+    assert_eq!(day5a::part1(&test_input(5, "a")).unwrap(), 820);
+}
+
+#[test]
+fn day5_seat_code() {
+    use day5a::seat_code::{CodeError, Geometry, Seat, SeatCode, PLANE};
+
+    let plane = SeatCode::new(PLANE);
+    assert_eq!(plane.decode("FBFBBFFRLR"), Ok(Seat { row: 44, column: 5 }));
+    assert_eq!(plane.decode_id("BBFFBBFRLL"), Ok(820));
+    assert_eq!(plane.encode(Seat { row: 70, column: 7 }).unwrap(), "BFFFBBFRRR");
+    assert_eq!(plane.encode_id(567).unwrap(), "BFFFBBFRRR");
+    for id in 0..PLANE.seats() {
+        assert_eq!(plane.decode_id(&plane.encode_id(id).unwrap()), Ok(id));
+    }
+
+    assert_eq!(plane.decode("FBFBBFFRL"), Err(CodeError::Length { expected: 10, found: 9 }));
+    assert_eq!(plane.decode("FBFBBFFRLB"), Err(CodeError::Character { position: 10, found: 'B', expected: "L or R" }));
+    assert_eq!(plane.decode("FBFLBFFRLR"), Err(CodeError::Character { position: 4, found: 'L', expected: "F or B" }));
+    assert_eq!(plane.encode_id(1024), Err(CodeError::SeatOutOfRange { id: 1024, seats: 1024 }));
+
+    // 100 rows still take 7 bits, and 6 columns take 3, but the codes past the end aren't seats.
+    let small = SeatCode::new(Geometry { rows: 100, columns: 6 });
+    assert_eq!(small.length(), 10);
+    assert_eq!(small.decode("BBFFFFFLRR"), Ok(Seat { row: 96, column: 3 }));
+    assert_eq!(small.decode_id("BBFFFFFLRR"), Ok(96 * 6 + 3));
+    assert_eq!(small.decode("BBFFFFFRRR"), Err(CodeError::ColumnOutOfRange { column: 7, columns: 6 }));
+    assert_eq!(small.decode("BBFFBBFLLL"), Err(CodeError::RowOutOfRange { row: 102, rows: 100 }));
+    assert_eq!(SeatCode::new(Geometry { rows: 1, columns: 3 }).encode_id(2).unwrap(), "RL");
+
+    // A fixture for part 2: a plane that's full from seat 10 to seat 200, apart from seat 123.
+    let boarding_passes: Vec<String> = (10..=200).filter(|id| *id != 123).map(|id| small.encode_id(id).unwrap()).collect();
+    let boarding_passes = day5a::parse_file_with(&Input::literal(&boarding_passes.join("\n")), &small).unwrap();
+    assert_eq!(day5b::missing_seat(&boarding_passes, small.geometry).unwrap(), 123);
+    assert_eq!(day5a::highest_seat_id(&boarding_passes).unwrap(), 200);
+}
+
+#[test]
+fn day5_seat_map() {
+    use day5a::seat_code::{Geometry, SeatCode};
+    use day5a::seat_map::{seat_map, EmptyRange, Gap};
+
+    // 4 rows of 3 seats, with seats 4 and 7 to 8 empty, and seat 5 given twice.
+    let seat_code = SeatCode::new(Geometry { rows: 4, columns: 3 });
+    let boarding_passes: Vec<String> = [2, 3, 5, 6, 9, 5].iter().map(|id| seat_code.encode_id(*id).unwrap()).collect();
+    let boarding_passes = day5a::parse_file_with(&Input::literal(&boarding_passes.join("\n")), &seat_code).unwrap();
+    let seat_map = seat_map(&boarding_passes, &seat_code);
+
+    assert_eq!(seat_map.empty, vec![
+        EmptyRange { gap: Gap::Front, first: 0, last: 1 },
+        EmptyRange { gap: Gap::Hole, first: 4, last: 4 },
+        EmptyRange { gap: Gap::Hole, first: 7, last: 8 },
+        EmptyRange { gap: Gap::Back, first: 10, last: 11 },
+    ]);
+    assert_eq!(seat_map.duplicates.get(&5), Some(&vec![3, 6]));
+    assert_eq!(seat_map.occupancy(), vec![1, 2, 1, 1]);
+    assert_eq!(seat_map.render(), "0 ..# 1
+1 #.! 2
+2 #.. 1
+3 #.. 1
+
+Empty at the front: 2 seats, 0 to 1 (row 0 column 0 to row 0 column 1)
+Hole: seat 4 (row 1 column 1)
+Hole: 2 seats, 7 to 8 (row 2 column 1 to row 2 column 2)
+Empty at the back: 2 seats, 10 to 11 (row 3 column 1 to row 3 column 2)
+Duplicate boarding passes for seat 5 (row 1 column 2) on lines 3, 6
+");
+    assert_eq!(day5b::missing_seat(&boarding_passes, seat_code.geometry).unwrap(), 4);
+
+    let json = seat_map.to_json();
+    assert_eq!(json["boarding_passes"], 6);
+    assert_eq!(json["empty"][2]["seats"], 2);
+    assert_eq!(json["duplicates"][0]["lines"][1], 6);
+    assert_eq!(json["map"][0], "..#");
+
+    let empty = day5a::seat_map::seat_map(&Vec::new(), &seat_code);
+    assert_eq!(empty.empty, vec![EmptyRange { gap: Gap::Front, first: 0, last: 11 }]);
+}
+
+#[test]
+fn day6() {
+    assert_eq!(day6a::part1(&test_input(6, "a")).unwrap(), 11);
+    assert_eq!(day6b::part2(&test_input(6, "b")).unwrap(), 6);
+}
+
+#[test]
+fn day6_queries() {
+    use day6a::analysis::{frequencies, outliers};
+    use day6a::{count, Answers, Query};
+
+    let groups = day6a::parse_file(&test_input(6, "a")).unwrap();
+    assert_eq!(count(&groups, Query::Any), 11);
+    assert_eq!(count(&groups, Query::All), 6);
+    assert_eq!(count(&groups, Query::AtLeast(1)), 11);
+    assert_eq!(count(&groups, Query::None), 5 * 26 - 11);
+    // Only "a" in the third and fourth groups was answered by more than one person.
+    assert_eq!(count(&groups, Query::Exactly(1)), 11 - 2);
+    assert_eq!(count(&groups, Query::AtLeast(2)), 2);
+
+    assert_eq!(Query::from_name("at-least=3"), Some(Query::AtLeast(3)));
+    assert_eq!(Query::from_name("exactly=0"), Some(Query::Exactly(0)));
+    assert_eq!(Query::from_name("most"), None);
+    assert_eq!(Query::from_name("exactly=x"), None);
+
+    assert_eq!(Answers::parse("zac"), Some(Answers(1 << 25 | 0b101)));
+    assert_eq!(Answers::parse("zac").unwrap().to_string(), "acz");
+    assert_eq!(Answers::parse("aB"), None);
+    assert!(day6a::parse_file(&Input::literal("ab\na-c")).is_err());
+
+    let frequencies = frequencies(&groups);
+    assert_eq!(frequencies.len(), 26);
+    assert_eq!((frequencies[0].people, frequencies[0].anyone, frequencies[0].everyone), (8, 4, 3));
+    assert_eq!(frequencies[25].people, 0);
+
+    let groups = day6a::parse_file(&Input::literal("abc\nabc\nabd\n\nab\nab\nxyz\n\nq\nq")).unwrap();
+    let outliers = outliers(&groups);
+    assert_eq!(outliers.len(), 2);
+    assert_eq!((outliers[0].group, outliers[0].line, outliers[0].answers.to_string()), (2, 7, String::from("xyz")));
+    assert_eq!(outliers[0].distance, 5.0);
+    assert_eq!((outliers[1].group, outliers[1].line, outliers[1].distance), (1, 3, 2.0));
+}
+
+#[test]
+fn day7() {
+    assert_eq!(day7a::part1(&test_input(7, "a")).unwrap(), 4);
+    assert_eq!(day7b::part2(&test_input(7, "b")).unwrap(), 126);
+}
+
+#[test]
+fn day7_graph() {
+    use day7a::{inner_bag_count, outer_bag_count, Error};
+
+    let graph = day7a::parse_file(&test_input(7, "a")).unwrap();
+    assert_eq!(graph.len(), 9);
+    // Both ways through the same graph, for any colour, with one counter for all of them.
+    let mut counter = graph.counter();
+    assert_eq!(outer_bag_count(&graph, "shiny gold").unwrap(), 4);
+    assert_eq!(inner_bag_count(&mut counter, "shiny gold").unwrap(), 32);
+    assert_eq!(outer_bag_count(&graph, "muted yellow").unwrap(), 2);
+    assert_eq!(inner_bag_count(&mut counter, "muted yellow").unwrap(), 2 * 33 + 9);
+    assert_eq!(outer_bag_count(&graph, "light red").unwrap(), 0);
+    assert_eq!(inner_bag_count(&mut counter, "faded blue").unwrap(), 0);
+
+    let light_red = graph.id("light red").unwrap();
+    assert_eq!(counter.inner_bags(light_red).unwrap(), (1 + 33) + 2 * (1 + 75));
+    assert!(matches!(outer_bag_count(&graph, "mauve"), Err(Error::UnknownBag(_))));
+
+    let rules = Input::literal("wavy red bags contain 2 pale blue bags.\npale blue bags contain 1 wavy red bag.\nfaded tan bags contain 3 drab cyan bags.");
+    let graph = day7a::parse_file(&rules).unwrap();
+    let mut counter = graph.counter();
+    assert!(matches!(inner_bag_count(&mut counter, "wavy red"), Err(Error::Cycle(_))));
+    // A failed count doesn't leave bags half counted.
+    assert!(matches!(inner_bag_count(&mut counter, "pale blue"), Err(Error::Cycle(path)) if path.len() == 3));
+    assert!(matches!(inner_bag_count(&mut counter, "faded tan"), Err(Error::UnknownBag(_))));
+    assert!(day7a::parse_file(&Input::literal("wavy red bags contain two pale blue bags.")).is_err());
+}
+
+#[test]
+fn day7_validate() {
+    use day7a::validate::{validate, Problem};
+
+    let graph = day7a::parse_file(&test_input(7, "a")).unwrap();
+    assert_eq!(validate(&graph), vec![]);
+
+    let rules = Input::literal(concat!(
+        "wavy red bags contain 2 pale blue bags.\n",
+        "pale blue bags contain 1 dim lime bag, 1 drab cyan bag.\n",
+        "dim lime bags contain 3 wavy red bags.\n",
+        "faded tan bags contain 3 drab cyan bags.\n",
+        "faded tan bags contain no other bags.\n",
+        "big teal bags contain 4294967296 huge teal bags.\n",
+        "huge teal bags contain 4294967296 plain teal bags.\n",
+        "plain teal bags contain no other bags.\n",
+        "giant teal bags contain 1 big teal bag.",
+    ));
+    let graph = day7a::parse_file(&rules).unwrap();
+    assert_eq!(validate(&graph), vec![
+        Problem::Cycle(vec![String::from("wavy red"), String::from("pale blue"), String::from("dim lime"), String::from("wavy red")]),
+        Problem::Undefined { bag: String::from("drab cyan"), lines: vec![2, 4] },
+        Problem::Duplicate { bag: String::from("faded tan"), lines: vec![4, 5] },
+        // Only the innermost bag that overflows is reported, not the bags it goes inside.
+        Problem::TooManyBags { bag: String::from("big teal") },
+    ]);
+    assert!(matches!(day7a::part1(&rules), Err(day7a::Error::Invalid(problems)) if problems.len() == 4));
+    assert!(matches!(day7a::inner_bag_count(&mut graph.counter(), "dim lime"), Err(day7a::Error::Cycle(path)) if path.len() == 4));
+}
+
+#[test]
+fn day7_deep_chain() {
+    // Each bag holds the next one, far deeper than recursion would get before overflowing the stack.
+    let depth = 200_000;
+    // Colours are only letters, so spell the numbers with them.
+    let colour = |bag: usize| format!("deep {}", bag.to_string().bytes().map(|digit| (digit - b'0' + b'a') as char).collect::<String>());
+    let mut rules: Vec<String> = (0..depth).map(|bag| format!("{} bags contain 1 {} bag.", colour(bag), colour(bag + 1))).collect();
+    rules.push(format!("{} bags contain no other bags.", colour(depth)));
+    let graph = day7a::parse_file(&Input::literal(&rules.join("\n"))).unwrap();
+
+    let mut counter = day7a::validate::check(&graph).unwrap();
+    assert_eq!(day7a::inner_bag_count(&mut counter, &colour(0)).unwrap(), depth);
+    assert_eq!(day7a::outer_bag_count(&graph, &colour(depth)).unwrap(), depth);
+    // Without the counts from checking the rules, counting has to go all the way down itself.
+    assert_eq!(day7a::inner_bag_count(&mut graph.counter(), &colour(0)).unwrap(), depth);
+}
+
+#[test]
+fn day7_export() {
+    use day7a::export::{select, to_dot, to_json, tree};
+
+    let graph = day7a::parse_file(&test_input(7, "a")).unwrap();
+    let id = |name: &str| graph.id(name).unwrap();
+
+    let all = select(&graph, None, None);
+    let dot = to_dot(&graph, &all);
+    assert!(dot.starts_with("digraph bags {\n    \"light red\";\n"));
+    assert!(dot.contains("    \"muted yellow\" -> \"faded blue\" [weight=9, label=\"9\"];\n"));
+    assert_eq!(dot.matches(" -> ").count(), 13);
+
+    // The bags on the way from light red to shiny gold.
+    let between = select(&graph, Some(id("light red")), Some(id("shiny gold")));
+    assert_eq!(to_json(&graph, &between).to_string(), concat!(
+        r#"{"light red":[{"bag":"bright white","count":1},{"bag":"muted yellow","count":2}],"#,
+        r#""bright white":[{"bag":"shiny gold","count":1}],"muted yellow":[{"bag":"shiny gold","count":2}],"shiny gold":[]}"#,
+    ));
+    assert_eq!(tree(&graph, &between, id("light red")), vec!["light red", "  1 bright white", "    1 shiny gold", "  2 muted yellow", "    4 shiny gold (2 x 2)"]);
+
+    let inside = select(&graph, Some(id("shiny gold")), None);
+    assert_eq!(inside.iter().filter(|selected| **selected).count(), 5);
+    let lines = tree(&graph, &inside, id("shiny gold"));
+    assert_eq!(lines[5], "    10 faded blue (2 x 5)");
+    // The counts in the tree add up to the answer to part 2.
+    let total: usize = lines[1..].iter().map(|line| line.trim().split(' ').next().unwrap().parse::<usize>().unwrap()).sum();
+    assert_eq!(total, 32);
+}
+
+#[test]
+fn day8() {
+    assert_eq!(day8a::part1(&test_input(8, "a")).unwrap(), 5);
+    assert_eq!(day8b::part2(&test_input(8, "b")).unwrap(), 8);
+}
+
+#[test]
+fn day8_vm() {
+    use day8vm::vm::{Effect, Machine, Opcode, Program, Termination, OPCODES};
+
+    let program = day8a::parse_file(&test_input(8, "a")).unwrap();
+    assert_eq!(program.instructions[4].to_string(), "jmp -3");
+
+    let mut machine = Machine::new(program.clone());
+    assert_eq!(machine.step(), None);
+    assert_eq!((machine.pc, machine.accumulator, machine.steps), (1, 0, 1));
+    assert_eq!(machine.run(), Termination::Loop { pc: 1 });
+    assert_eq!((machine.accumulator, machine.steps), (5, 7));
+    // Stopping doesn't change anything, so asking again gives the same reason.
+    assert_eq!(machine.step(), Some(Termination::Loop { pc: 1 }));
+
+    machine.reset();
+    machine.detect_loops = false;
+    machine.step_limit = Some(20);
+    assert_eq!(machine.run(), Termination::StepLimit { steps: 20 });
+
+    let mut machine = Machine::new(day8a::parse_file(&Input::literal("nop +0\nacc +3\njmp -3")).unwrap());
+    assert_eq!(machine.run(), Termination::OutOfBounds { pc: 2, target: -1 });
+    assert_eq!((machine.pc, machine.accumulator), (2, 3));
+    assert!(matches!(day8a::part1(&Input::literal("acc +1\njmp +5")), Err(day8a::Error::Stopped(Termination::OutOfBounds { pc: 1, target: 6 }))));
+    assert!(matches!(day8a::part1(&Input::literal("acc +1\njmp +1")), Err(day8a::Error::NoLoop)));
+
+    let mut machine = Machine::new(program);
+    machine.accumulator = i64::MAX;
+    machine.pc = 1;
+    assert_eq!(machine.run(), Termination::Overflow { pc: 1 });
+
+    // A table with an extra instruction that doubles the argument.
+    fn dbl(argument: i32) -> Effect {
+        return Effect { accumulator: 2 * argument as i64, jump: 1 };
+    }
+    let mut opcodes = OPCODES.to_vec();
+    opcodes.push(Opcode { name: "dbl", execute: dbl });
+    let doubling = Input::literal("dbl +4\nacc -1");
+    let mut machine = Machine::new(Program::parse_with(&doubling, &opcodes).unwrap());
+    assert_eq!((machine.run(), machine.accumulator), (Termination::Exit, 7));
+    let error = day8a::parse_file(&doubling).unwrap_err();
+    assert_eq!(error.to_string(), "Unknown instruction \"dbl\" on line 1 (\"dbl +4\"), expected nop, acc or jmp");
+}
+
+#[test]
+fn day8_debugger() {
+    use day8vm::debugger::{Breakpoint, Debugger, Watch};
+
+    let mut debugger = Debugger::new(day8a::parse_file(&test_input(8, "a")).unwrap());
+    assert_eq!(debugger.execute("step 2"), vec!["At line 3: jmp +4 (accumulator 1, 2 steps)"]);
+    assert_eq!(debugger.execute("break jmp"), vec!["Breakpoint on every jmp"]);
+    // Carrying on from a breakpoint runs the instruction it's on.
+    assert_eq!(debugger.execute("continue"), vec!["Breakpoint on every jmp at line 8: jmp -4 (accumulator 2, 4 steps)"]);
+    assert_eq!(debugger.execute("delete jmp"), vec!["Removed the breakpoint on every jmp"]);
+    assert_eq!(debugger.execute("watch 5"), vec!["Watching for the accumulator to become 5"]);
+    assert_eq!(debugger.watch, Some(Watch::Value(5)));
+    assert_eq!(debugger.execute("c"), vec!["The accumulator went from 2 to 5 on line 4, now at line 5: jmp -3 (accumulator 5, 6 steps)"]);
+    assert_eq!(debugger.execute("c"), vec!["Stopped because line 2 was about to run a second time, at line 2: acc +1 (accumulator 5, 7 steps)"]);
+    assert_eq!(debugger.execute("history 2"), vec!["line 4: acc +3 -> accumulator 5", "line 5: jmp -3 -> accumulator 5"]);
+    assert_eq!(debugger.history.len(), 7);
+
+    // Repairing the program the way part 2 does, then running it again.
+    assert_eq!(debugger.execute("patch 8 nop -4"), vec!["Line 8 is now nop -4 (it was jmp -4), type run to start again"]);
+    assert_eq!(debugger.execute("unwatch"), vec!["Not watching the accumulator"]);
+    assert_eq!(debugger.execute("break 9"), vec!["Breakpoint on line 9"]);
+    assert_eq!(debugger.breakpoints, vec![Breakpoint::Line(8)]);
+    assert_eq!(debugger.execute("run"), vec!["Breakpoint on line 9 at line 9: acc +6 (accumulator 2, 5 steps)"]);
+    assert_eq!(debugger.execute("list 9")[4..], ["   ~ 8  nop -4   ran 1", "=>*  9  acc +6   ran 0"]);
+    assert_eq!(debugger.execute("c"), vec!["Stopped because the program finished, at the end of the program (accumulator 8, 6 steps)"]);
+
+    assert_eq!(debugger.execute("break 10"), vec!["Expected a line number from 1 to 9 or one of nop, acc or jmp, found '10'"]);
+    assert_eq!(debugger.execute("patch 1 mul +2"), vec!["Unknown instruction \"mul\" on line 1 (\"mul +2\"), expected nop, acc or jmp"]);
+    assert_eq!(debugger.execute("frobnicate"), vec!["Unknown command 'frobnicate', type help for the commands"]);
+    assert!(!debugger.quit);
+    assert_eq!(debugger.execute("quit"), Vec::<String>::new());
+    assert!(debugger.quit);
+}
+
+#[test]
+fn day9() {
+    // The example uses a window of 5 numbers rather than 25.
+    let numbers = day9a::parse_file(&test_input(9, "a")).unwrap();
+    assert_eq!(day9a::solve_with_window(&numbers, 5).unwrap(), 127);
+
+    let numbers = day9b::parse_file(&test_input(9, "b")).unwrap();
+    assert_eq!(day9b::solve_with_window(&numbers, 5).unwrap(), 62);
+}
+
+#[test]
+fn day10() {
+    assert_eq!(day10a::part1(&test_input(10, "a")).unwrap(), 220);
+    assert_eq!(day10b::part2(&test_input(10, "b")).unwrap(), 19208);
+}
+
+#[test]
+fn day11() {
+    assert_eq!(day11a::part1(&test_input(11, "a")).unwrap(), 37);
+    assert_eq!(day11b::part2(&test_input(11, "b")).unwrap(), 26);
+}
+
+#[test]
+fn day12() {
+    assert_eq!(day12a::part1(&test_input(12, "a")).unwrap(), 25);
+    assert_eq!(day12b::part2(&test_input(12, "b")).unwrap(), 286);
+}
+
+#[test]
+fn day12_turns() {
+    use day12a::{Heading, Instruction};
+
+    // Turning left is turning right the rest of the way round.
+    let instructions = day12a::parse_file(&Input::literal("L90\nR450\nL0")).unwrap();
+    assert_eq!(instructions, vec![Instruction::Turn { quarter_turns: 3 }, Instruction::Turn { quarter_turns: 1 }, Instruction::Turn { quarter_turns: 0 }]);
+    assert_eq!(Heading::East.turned(3), Heading::North);
+    assert!(day12a::parse_file(&Input::literal("R45")).is_err());
+    assert!(day12b::parse_file(&Input::literal("X10")).is_err());
+    assert_eq!(day12b::part2(&Input::literal("L270\nF1")).unwrap(), 1 + 10);
+
+    // A ship that goes too far is an error rather than a wrapped or panicking sum.
+    let far = Input::literal("E10\nF2000000000\nF2000000000");
+    assert!(matches!(day12a::part1(&far), Err(day12a::Error::Overflow { line: 3 })));
+    assert!(matches!(day12b::part2(&far), Err(day12b::Error::Overflow { line: 2 })));
+}
+
+#[test]
+fn day13() {
+    assert_eq!(day13a::part1(&test_input(13, "a")).unwrap(), 295);
+    assert_eq!(day13b::part2(&test_input(13, "b")).unwrap(), 1202161486);
+}
+
+#[test]
+fn day14() {
+    assert_eq!(day14a::part1(&test_input(14, "a")).unwrap(), 165);
+    assert_eq!(day14b::part2(&test_input(14, "b")).unwrap(), 208);
+}
+
+#[test]
+fn day15() {
+    assert_eq!(day15a::part1(&test_input(15, "a")).unwrap(), 1836);
+    assert_eq!(day15b::part2(&test_input(15, "b")).unwrap(), 175594);
+}
+
+#[test]
+fn day16() {
+    assert_eq!(day16a::part1(&test_input(16, "a")).unwrap(), 71);
+    // The example has no departure fields, so this only checks that the fields can be worked out.
+    assert_eq!(day16b::part2(&test_input(16, "b")).unwrap(), 1);
+}
+
+#[test]
+fn day17() {
+    assert_eq!(day17a::part1(&test_input(17, "a")).unwrap(), 112);
+    assert_eq!(day17b::part2(&test_input(17, "b")).unwrap(), 848);
+}
+
+#[test]
+fn day18() {
+    // The sums of the example expressions in each test_input.
+    assert_eq!(day18a::part1(&test_input(18, "a")).unwrap(), 51 + 13632);
+    assert_eq!(day18b::part2(&test_input(18, "b")).unwrap(), 231 + 51 + 46 + 1445 + 669060 + 23340);
+}
+
+#[test]
+fn day18_overflow() {
+    let huge = Input::literal("2\n3037000500 * 3037000500");
+    assert!(matches!(day18a::part1(&huge), Err(day18a::Error::Overflow { line: 2 })));
+    assert!(matches!(day18b::part2(&huge), Err(day18b::Error::Overflow { line: 2 })));
+    let sum = Input::literal("9223372036854775807\n1");
+    assert!(matches!(day18a::part1(&sum), Err(day18a::Error::Overflow { line: 2 })));
+    assert!(day18b::part2(&Input::literal("1 + x")).is_err());
+}
+
+#[test]
+fn day19() {
+    assert_eq!(day19a::part1(&test_input(19, "a")).unwrap(), 2);
+    assert_eq!(day19b::part2(&test_input(19, "b")).unwrap(), 12);
+}
+
+#[test]
+fn day20() {
+    assert_eq!(day20a::part1(&test_input(20, "a")).unwrap(), 20899048083289);
+    assert_eq!(day20b::part2(&test_input(20, "b")).unwrap(), 273);
+}
+
+#[test]
+fn day20_tiles() {
+    assert!(matches!(day20a::part1(&Input::literal("Tile 1:\n#")), Err(day20a::Error::TooSmall { id: 1, size: 1 })));
+    assert!(matches!(day20b::part2(&Input::literal("Tile 1:\n#")), Err(day20b::Error::TooSmall { id: 1, size: 1 })));
+    assert!(matches!(day20a::part1(&Input::literal("Tile 1:\n#.\n.")), Err(day20a::Error::Malformed { line: 3, .. })));
+    assert!(matches!(day20a::part1(&Input::literal("Tile 1:\n#.\n.#\n\nTile 2:\n#.\n.#")), Err(day20a::Error::NotSquare(2))));
+
+    // A single tile is a whole board, with itself in every corner.
+    assert_eq!(day20a::part1(&Input::literal("Tile 3:\n#.\n.#")).unwrap(), 81);
+    assert!(matches!(day20a::part1(&Input::literal("Tile 9999999999:\n#.\n.#")), Err(day20a::Error::TooLarge)));
+    assert!(matches!(day20b::part2(&Input::literal("Tile 3:\n#..\n.#.\n..#")), Err(day20b::Error::NoSeaMonsters)));
+}
+
+#[test]
+fn day21() {
+    assert_eq!(day21a::part1(&test_input(21, "a")).unwrap(), 5);
+    assert_eq!(day21b::part2(&test_input(21, "b")).unwrap(), "mxmxvkd,sqjhc,fvjkl");
+}
+
+#[test]
+fn day22() {
+    assert_eq!(day22a::part1(&test_input(22, "a")).unwrap(), 306);
+    assert_eq!(day22b::part2(&test_input(22, "b")).unwrap(), 291);
+}
+
+#[test]
+fn day23() {
+    let example = Input::literal("389125467");
+    assert_eq!(day23a::part1(&example).unwrap(), "67384529");
+    assert_eq!(day23b::part2(&example).unwrap(), 149245887792);
+}
+
+#[test]
+fn day24() {
+    assert_eq!(day24a::part1(&test_input(24, "a")).unwrap(), 10);
+    assert_eq!(day24b::part2(&test_input(24, "b")).unwrap(), 2208);
+}
+
+#[test]
+fn day25() {
+    assert_eq!(day25a::part1(&Input::literal("5764801 17807724")).unwrap(), 14897079);
+}
+
+#[test]
+fn details() {
+    let (_, details) = day1a::part1_with_details(&test_input(1, "a")).unwrap();
+    assert_eq!(details["entries"], json!([1721, 299]));
+    assert_eq!(details["indices"], json!([0, 3]));
+
+    let (_, details) = day18a::part1_with_details(&test_input(18, "a")).unwrap();
+    assert_eq!(details["values"], json!([{ "line": 1, "value": 51 }, { "line": 2, "value": 13632 }]));
+
+    let (_, details) = day21a::part1_with_details(&test_input(21, "a")).unwrap();
+    assert_eq!(details["safe_ingredients"], json!(["kfcds", "nhms", "sbzzf", "trh"]));
+    let (_, details) = day21b::part2_with_details(&test_input(21, "b")).unwrap();
+    assert_eq!(details["ingredients"][0], json!({ "allergen": "dairy", "ingredient": "mxmxvkd" }));
+
+    let (_, details) = day23a::part1_with_details(&Input::literal("389125467")).unwrap();
+    assert_eq!(details["cups"], json!([1, 6, 7, 3, 8, 4, 5, 2, 9]));
+}
@@ -0,0 +1,146 @@
+// Scaffolds days into a throwaway copy of the files `aoc new` registers with, and checks where they end up.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc::scaffold;
+
+const WORKSPACE_MANIFEST: &str = "[workspace]
+members = [
+    \"aoc\",
+    \"common\",
+    \"1/b\",
+    \"3/a\", \"3/b\",
+]
+";
+
+const RUNNER_MANIFEST: &str = "[dependencies]
+aoc_common = { path = \"../common\" }
+day1b = { path = \"../1/b\" }
+day3a = { path = \"../3/a\" }
+day3b = { path = \"../3/b\" }
+
+[lints]
+workspace = true
+";
+
+const SOLUTIONS: &str = "pub static SOLUTIONS: &[Solution] = &[
+    Solution { day: 1, part: 2, run: |input| Ok(day1b::part2(input)?.to_string()), details: |input| with_details(day1b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day1b::parse_file, day1b::solve) },
+    Solution { day: 3, part: 1, run: |input| Ok(day3a::part1(input)?.to_string()), details: |input| with_details(day3a::part1_with_details(input)), bench: |input, samples| bench::measure(input, samples, day3a::parse_file, day3a::solve) },
+    Solution { day: 3, part: 2, run: |input| Ok(day3b::part2(input)?.to_string()), details: |input| with_details(day3b::part2_with_details(input)), bench: |input, samples| bench::measure(input, samples, day3b::parse_file, day3b::solve) },
+];
+";
+
+const EXAMPLES: &str = "#[test]
+fn day1() {
+    assert_eq!(day1b::part2(&test_input(1, \"b\")).unwrap(), 241861950);
+}
+
+#[test]
+fn day3() {
+    assert_eq!(day3a::part1(&test_input(3, \"a\")).unwrap(), 7);
+    assert_eq!(day3b::part2(&test_input(3, \"b\")).unwrap(), 336);
+}
+";
+
+fn workspace(name: &str) -> PathBuf {
+    let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+    fs::create_dir_all(root.join("aoc").join("tests")).unwrap();
+    fs::create_dir_all(root.join("1").join("b")).unwrap();
+    fs::write(root.join("1").join("question.txt"), "Report Repair").unwrap();
+    fs::write(root.join("Cargo.toml"), WORKSPACE_MANIFEST).unwrap();
+    fs::write(root.join("aoc").join("Cargo.toml"), RUNNER_MANIFEST).unwrap();
+    fs::write(root.join("aoc").join("src").join("solutions.rs"), SOLUTIONS).unwrap();
+    fs::write(root.join("aoc").join("tests").join("examples.rs"), EXAMPLES).unwrap();
+    return root;
+}
+
+fn read(root: &Path, file_name: &str) -> String {
+    return fs::read_to_string(root.join(file_name)).unwrap();
+}
+
+#[test]
+fn new_day() {
+    let root = workspace("new-day");
+    let created = scaffold::create_day(&root, 2, &[1, 2]).unwrap();
+
+    assert_eq!(created.len(), 11);
+    assert!(created.contains(&Path::new("2").join("question.txt")));
+    for part in &["a", "b"] {
+        for file_name in &["Cargo.toml", "input", "test_input"] {
+            assert!(root.join("2").join(part).join(file_name).is_file());
+        }
+        assert!(root.join("2").join(part).join("src").join("lib.rs").is_file());
+        assert!(root.join("2").join(part).join("src").join("main.rs").is_file());
+    }
+    assert!(read(&root, "2/a/Cargo.toml").contains("name = \"day2a\""));
+    assert!(read(&root, "2/b/src/lib.rs").contains("pub fn part2(input: &Input)"));
+    assert!(read(&root, "2/b/src/lib.rs").contains("pub fn part2_with_details(input: &Input)"));
+    assert!(read(&root, "2/b/src/main.rs").contains("use day2b::part2_with_details;"));
+
+    assert!(read(&root, "Cargo.toml").contains("    \"1/b\",\n    \"2/a\", \"2/b\",\n    \"3/a\", \"3/b\",\n"));
+    assert!(read(&root, "aoc/Cargo.toml").contains("day1b = { path = \"../1/b\" }\nday2a = { path = \"../2/a\" }\nday2b = { path = \"../2/b\" }\nday3a"));
+    let solutions = read(&root, "aoc/src/solutions.rs");
+    assert!(solutions.find("day: 1, part: 2").unwrap() < solutions.find("day: 2, part: 1").unwrap());
+    assert!(solutions.find("day: 2, part: 2").unwrap() < solutions.find("day: 3, part: 1").unwrap());
+    assert!(solutions.contains("bench::measure(input, samples, day2b::parse_file, day2b::solve)"));
+    let examples = read(&root, "aoc/tests/examples.rs");
+    assert!(examples.contains("#[test]\nfn day2() {\n"));
+    assert!(examples.contains("    assert_eq!(day2a::part1(&test_input(2, \"a\")).unwrap(), 0);\n    assert_eq!(day2b::part2(&test_input(2, \"b\")).unwrap(), 0);\n}\n\n#[test]\nfn day3() {"));
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn missing_part_of_existing_day() {
+    let root = workspace("missing-part");
+    let created = scaffold::create_day(&root, 1, &[1]).unwrap();
+
+    // The question is already there, so only the new crate is created.
+    assert_eq!(created.len(), 5);
+    assert_eq!(read(&root, "1/question.txt"), "Report Repair");
+    assert!(read(&root, "Cargo.toml").contains("    \"1/a\", \"1/b\",\n"));
+    assert!(read(&root, "aoc/tests/examples.rs").starts_with("#[test]
+fn day1() {
+    assert_eq!(day1a::part1(&test_input(1, \"a\")).unwrap(), 0);
+    assert_eq!(day1b::part2(&test_input(1, \"b\")).unwrap(), 241861950);
+}
+"));
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn existing_part() {
+    let root = workspace("existing-part");
+    assert!(scaffold::create_day(&root, 1, &[2]).is_err());
+    assert_eq!(read(&root, "Cargo.toml"), WORKSPACE_MANIFEST);
+    assert_eq!(read(&root, "aoc/src/solutions.rs"), SOLUTIONS);
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn last_day() {
+    let root = workspace("last-day");
+    scaffold::create_day(&root, 25, &[1, 2]).unwrap();
+
+    assert!(read(&root, "Cargo.toml").ends_with("    \"3/a\", \"3/b\",\n    \"25/a\", \"25/b\",\n]\n"));
+    assert!(read(&root, "aoc/Cargo.toml").contains("day3b = { path = \"../3/b\" }\nday25a = { path = \"../25/a\" }\nday25b = { path = \"../25/b\" }\n\n[lints]"));
+    assert!(read(&root, "aoc/src/solutions.rs").ends_with("day25b::solve) },\n];\n"));
+    assert!(read(&root, "aoc/tests/examples.rs").ends_with("    assert_eq!(day3b::part2(&test_input(3, \"b\")).unwrap(), 336);
+}
+
+#[test]
+fn day25() {
+    // Replace the 0s with the answers to the example in question.txt once it's in test_input.
+    assert_eq!(day25a::part1(&test_input(25, \"a\")).unwrap(), 0);
+    assert_eq!(day25b::part2(&test_input(25, \"b\")).unwrap(), 0);
+}
+"));
+
+    fs::remove_dir_all(&root).unwrap();
+}
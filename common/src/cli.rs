@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::logging;
+use crate::Input;
+
+// An option that only some days' binaries accept, such as `--policy <name>`. Options without a value are flags.
+pub struct DayOption {
+    pub name: &'static str,
+    pub value: Option<&'static str>,
+    pub description: &'static str,
+}
+
+// The command line every day's binary accepts.
+pub struct Options {
+    pub input: Input,
+    pub json: bool,
+    // The day's own options that were given, flags with an empty value.
+    day_options: HashMap<&'static str, String>,
+}
+
+impl Options {
+    pub fn value(&self, name: &str) -> Option<&str> {
+        return self.day_options.get(name).map(|value| value.as_str());
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        return self.day_options.contains_key(name);
+    }
+}
+
+fn usage(program: &str, day_options: &[DayOption]) -> String {
+    let mut day_usage = String::new();
+    for option in day_options {
+        let name = match option.value {
+            Some(value) => format!("--{} {}", option.name, value),
+            None => format!("--{}", option.name),
+        };
+        day_usage += &format!("\n    {:<31}{}", name, option.description);
+    }
+
+    return format!("Usage: {} [options] <input file | - | --input-file <file> | --input <text>>
+
+Input:
+    <file>, --input-file <file>    Read the puzzle input from a file
+    -                              Read the puzzle input from standard input
+    --input <text>                 Use <text> as the puzzle input, for puzzles that fit on one line
+
+Options:
+    --json                         Print the answer as a JSON object
+    -v, -vv                        Log what the solver is doing to stderr, -vv for every step
+    -q, --quiet                    Don't log anything, not even warnings
+    -h, --help                     Show this message{}", program, day_usage);
+}
+
+fn exit_with_usage(program: &str, day_options: &[DayOption], message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, usage(program, day_options));
+    process::exit(1);
+}
+
+// Reads the options from the command line, exiting with the usage text if they don't make sense,
+// and sets up logging at the level they ask for.
+pub fn parse_args() -> Options {
+    return parse_args_with(&[]);
+}
+
+// The same, for a binary that also takes options of its own.
+pub fn parse_args_with(day_options: &[DayOption]) -> Options {
+    let mut args = env::args();
+    let program = args
+        .next()
+        .and_then(|program| Path::new(&program).file_name().map(|name| name.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| String::from("aoc"));
+
+    let mut input = None;
+    let mut json = false;
+    let mut verbosity = 0;
+    let mut quiet = false;
+    let mut given = HashMap::new();
+    while let Some(arg) = args.next() {
+        let next_input = match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", usage(&program, day_options));
+                process::exit(0);
+            },
+            "--json" => {
+                json = true;
+                continue;
+            },
+            "-v" | "-vv" => {
+                verbosity += arg.len() - 1;
+                continue;
+            },
+            "-q" | "--quiet" => {
+                quiet = true;
+                continue;
+            },
+            "--input-file" => match args.next() {
+                Some(file_name) => Input::File(PathBuf::from(file_name)),
+                None => exit_with_usage(&program, day_options, "--input-file expects a file"),
+            },
+            "--input" => match args.next() {
+                Some(text) => Input::literal(&text),
+                None => exit_with_usage(&program, day_options, "--input expects the puzzle input"),
+            },
+            "-" => match Input::stdin() {
+                Err(why) => crate::exit_with_error(why),
+                Ok(input) => input,
+            },
+            _ if arg.starts_with("--") => {
+                let option = match day_options.iter().find(|option| option.name == &arg[2..]) {
+                    None => exit_with_usage(&program, day_options, &format!("Unknown option '{}'", arg)),
+                    Some(option) => option,
+                };
+                let value = match option.value {
+                    None => String::new(),
+                    Some(value) => match args.next() {
+                        Some(given_value) => given_value,
+                        None => exit_with_usage(&program, day_options, &format!("{} expects {}", arg, value)),
+                    },
+                };
+                given.insert(option.name, value);
+                continue;
+            },
+            _ => Input::File(PathBuf::from(arg)),
+        };
+
+        if input.is_some() {
+            exit_with_usage(&program, day_options, "Expected a single input");
+        }
+        input = Some(next_input);
+    }
+
+    logging::init(logging::level_filter(verbosity, quiet));
+
+    return match input {
+        None => exit_with_usage(&program, day_options, "Expected an input"),
+        Some(input) => Options { input: input, json: json, day_options: given },
+    };
+}
@@ -0,0 +1,199 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, Read};
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+
+pub use serde_json::{json, Value};
+
+pub mod cli;
+pub mod logging;
+pub mod output;
+
+#[derive(Debug)]
+pub enum InputError {
+    Open { file_name: String, source: io::Error },
+    Read { file_name: String, line: usize, source: io::Error },
+    Parse { file_name: String, line: usize, text: String, reason: String },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            InputError::Open { file_name, source } => write!(f, "Couldn't open {}: {}", file_name, source),
+            InputError::Read { file_name, line, source } => write!(f, "Couldn't read line {} from {}: {}", line, file_name, source),
+            InputError::Parse { file_name, line, text, reason } => write!(f, "Couldn't parse line {} of {} (\"{}\"): {}", line, file_name, text, reason),
+        };
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            InputError::Open { source, .. } => Some(source),
+            InputError::Read { source, .. } => Some(source),
+            InputError::Parse { .. } => None,
+        };
+    }
+}
+
+// Where a puzzle's input comes from: a file, or text that has already been read from stdin or given on the command line.
+#[derive(Debug, Clone)]
+pub enum Input {
+    File(PathBuf),
+    Text { name: String, text: String },
+}
+
+const STDIN_NAME: &str = "standard input";
+
+impl Input {
+    pub fn stdin() -> Result<Input, InputError> {
+        let mut text = String::new();
+        return match io::stdin().read_to_string(&mut text) {
+            Err(why) => Err(InputError::Open { file_name: String::from(STDIN_NAME), source: why }),
+            Ok(_) => Ok(Input::Text { name: String::from(STDIN_NAME), text: text }),
+        };
+    }
+
+    // Whether the input was read from stdin, which leaves nothing there for anything else to read.
+    pub fn is_stdin(&self) -> bool {
+        return matches!(self, Input::Text { name, .. } if name == STDIN_NAME);
+    }
+
+    pub fn literal(text: &str) -> Input {
+        return Input::Text { name: String::from("the --input argument"), text: String::from(text) };
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Text { name, .. } => write!(f, "{}", name),
+        };
+    }
+}
+
+fn parse_error(input: &Input, line: usize, text: &str, reason: impl fmt::Display) -> InputError {
+    return InputError::Parse {
+        file_name: input.to_string(),
+        line: line,
+        text: String::from(text),
+        reason: reason.to_string(),
+    };
+}
+
+// Reports why a binary couldn't produce an answer and exits with a failure status.
+pub fn exit_with_error(why: impl fmt::Display) -> ! {
+    eprintln!("Error: {}", why);
+    process::exit(1);
+}
+
+// A group of consecutive non-blank lines. `line` is the (1 based) line number of the first one.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub line: usize,
+    pub lines: Vec<String>,
+}
+
+pub fn read_lines(input: &Input) -> Result<Vec<String>, InputError> {
+    let file_name = match input {
+        Input::File(file_name) => file_name,
+        Input::Text { text, .. } => return Ok(text.lines().map(String::from).collect()),
+    };
+
+    let file = match File::open(file_name) {
+        Err(why) => return Err(InputError::Open { file_name: file_name.display().to_string(), source: why }),
+        Ok(file) => file
+    };
+
+    let mut lines = Vec::new();
+    for (index, line) in io::BufReader::new(file).lines().enumerate() {
+        match line {
+            Err(why) => return Err(InputError::Read { file_name: file_name.display().to_string(), line: index + 1, source: why }),
+            Ok(line) => lines.push(line),
+        }
+    }
+
+    return Ok(lines);
+}
+
+pub fn parse_lines<T>(input: &Input) -> Result<Vec<T>, InputError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    return parse_lines_with(input, |line_number, line| match line.parse::<T>() {
+        Err(why) => Err(parse_error(input, line_number, line, why)),
+        Ok(value) => Ok(value),
+    });
+}
+
+// Parses every line with `parse`, which is given the (1 based) line number so it can report where it failed.
+pub fn parse_lines_with<T, E, F>(input: &Input, mut parse: F) -> Result<Vec<T>, E>
+where
+    E: From<InputError>,
+    F: FnMut(usize, &str) -> Result<T, E>,
+{
+    let lines = read_lines(input)?;
+
+    let mut values = Vec::with_capacity(lines.len());
+    for (index, line) in lines.iter().enumerate() {
+        values.push(parse(index + 1, line)?);
+    }
+
+    return Ok(values);
+}
+
+pub fn read_records(input: &Input) -> Result<Vec<Record>, InputError> {
+    let lines = read_lines(input)?;
+
+    let mut records = Vec::new();
+    let mut current: Option<Record> = None;
+    for (index, line) in lines.into_iter().enumerate() {
+        if line.trim().is_empty() {
+            records.extend(current.take());
+            continue;
+        }
+
+        current.get_or_insert_with(|| Record { line: index + 1, lines: Vec::new() }).lines.push(line);
+    }
+    records.extend(current);
+
+    return Ok(records);
+}
+
+pub fn read_grid(input: &Input) -> Result<Vec<Vec<char>>, InputError> {
+    return parse_grid(input, "any character", Some);
+}
+
+// `expected` describes the cells `parse` accepts, for the error when it rejects one.
+pub fn parse_grid<T, F>(input: &Input, expected: &str, mut parse: F) -> Result<Vec<Vec<T>>, InputError>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    let lines = read_lines(input)?;
+
+    let mut grid: Vec<Vec<T>> = Vec::with_capacity(lines.len());
+    for (index, line) in lines.iter().enumerate() {
+        let mut row = Vec::with_capacity(line.len());
+        for (column, cell) in line.chars().enumerate() {
+            match parse(cell) {
+                None => return Err(parse_error(input, index + 1, line, format!("unexpected '{}' in column {}, expected {}", cell, column + 1, expected))),
+                Some(value) => row.push(value),
+            }
+        }
+
+        if let Some(first_row) = grid.first() {
+            if row.len() != first_row.len() {
+                return Err(parse_error(input, index + 1, line, format!("expected {} columns, found {}", first_row.len(), row.len())));
+            }
+        }
+
+        grid.push(row);
+    }
+
+    return Ok(grid);
+}
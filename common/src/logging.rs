@@ -0,0 +1,41 @@
+use log::{LevelFilter, Log, Metadata, Record};
+
+// Diagnostics go to stderr so they never get mixed up with the answer (or its JSON) on stdout.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        return metadata.level() <= log::max_level();
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+// `--quiet` turns everything off. `-v` adds what the solver found (info) to the default of warnings, and `-vv` adds
+// how it got there (debug and trace) too.
+pub fn level_filter(verbosity: usize, quiet: bool) -> LevelFilter {
+    if quiet {
+        return LevelFilter::Off;
+    }
+
+    return match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        _ => LevelFilter::Trace,
+    };
+}
+
+pub fn init(level: LevelFilter) {
+    // This only fails if a logger is already set, in which case that one is kept.
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level);
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+// The answer is always a string, so large answers survive JSON readers that only have doubles.
+pub fn answer_json(day: usize, part: usize, answer: &dyn Display, elapsed: Duration, details: Value) -> Value {
+    return json!({
+        "day": day,
+        "part": part,
+        "answer": answer.to_string(),
+        "elapsed_ms": elapsed.as_secs_f64() * 1000.0,
+        "details": details,
+    });
+}
+
+// Quotes a CSV field if it needs it, doubling any quotes inside.
+pub fn csv_field(value: &str) -> String {
+    if value.contains(&[',', '"', '\n', '\r'][..]) {
+        return format!("\"{}\"", value.replace('"', "\"\""));
+    }
+
+    return String::from(value);
+}
+
+pub fn csv_row(values: &[String]) -> String {
+    return values.iter().map(|value| csv_field(value)).collect::<Vec<String>>().join(",");
+}
+
+pub fn print_json(day: usize, part: usize, answer: &dyn Display, elapsed: Duration, details: Value) {
+    println!("{}", answer_json(day, part, answer, elapsed, details));
+}